@@ -0,0 +1,356 @@
+use {
+    crate::{ContainerAttrs, FieldAttrs, Shortcuts},
+    proc_macro2::{Span, TokenStream},
+    quote::{ToTokens, quote},
+    syn::{
+        Data, DeriveInput, Error, Expr, Fields, GenericArgument, Ident, Index, Path, PathArguments,
+        Result, Token, Type,
+        parse::{Parse, ParseStream},
+        parse_quote,
+    },
+};
+
+// general description of how the macro works:
+// - `PartialEq::eq` can't be called in a const context, so we can't just
+//   compare each field with `==`
+// - instead, we look at the type of each field, and generate a comparison based
+//   on how that type is written:
+//   - primitives are compared with `==`, which works in a const context
+//   - tuples, arrays, `Option` and `Result` are compared element-by-element
+//   - any other type must have its own `const_eq`, e.g. by deriving `ConstEq`
+// - we only see the type as it's written, so we can't compare values of a type
+//   parameter, and we can't see through type aliases - fields like this need a
+//   `const_eq = ..` attribute
+// - to avoid misreading a user's own type called e.g. `Option`, std types are
+//   only recognized by their bare name or their full path
+// - `const_eq!` runs the same comparison on a single type
+
+const PRIMITIVES: &[&str] = &[
+    "bool", "char", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128",
+    "isize", "f32", "f64",
+];
+
+pub fn derive(input: &DeriveInput) -> Result<TokenStream> {
+    let ContainerAttrs { krate, .. } = ContainerAttrs::parse(input)?;
+    let cx = Context {
+        shortcuts: Shortcuts::new(&krate),
+        type_params: input
+            .generics
+            .type_params()
+            .map(|param| &param.ident)
+            .collect(),
+    };
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let left = pattern(&data.fields, quote! { Self }, "l");
+            let right = pattern(&data.fields, quote! { Self }, "r");
+            let eq = cx.fields_eq(&data.fields)?;
+            quote! {
+                let #left = self;
+                let #right = other;
+                #eq
+            }
+        }
+        Data::Enum(data) => {
+            let arms = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+                    let left = pattern(&variant.fields, quote! { Self::#ident }, "l");
+                    let right = pattern(&variant.fields, quote! { Self::#ident }, "r");
+                    let eq = cx.fields_eq(&variant.fields)?;
+                    Ok(quote! {
+                        (#left, #right) => #eq,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            match data.variants.len() {
+                0 => quote! { match *self {} },
+                1 => quote! {
+                    match (self, other) {
+                        #(#arms)*
+                    }
+                },
+                _ => quote! {
+                    match (self, other) {
+                        #(#arms)*
+                        _ => false,
+                    }
+                },
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                input,
+                "const equality on union is not supported",
+            ));
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #name #type_generics #where_clause {
+            /// Checks if `self` and `other` are equal.
+            ///
+            /// This is the same as `==`, but can be used in a const context.
+            #[must_use]
+            pub const fn const_eq(&self, other: &Self) -> bool {
+                #body
+            }
+        }
+    })
+}
+
+// e.g. `Option<bool>, &a, &b`
+pub struct Input {
+    ty: Type,
+    a: Expr,
+    b: Expr,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ty = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let a = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let b = input.parse()?;
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+        Ok(Self { ty, a, b })
+    }
+}
+
+pub fn expand(input: &Input) -> Result<TokenStream> {
+    let Input { ty, a, b } = input;
+    let cx = Context {
+        shortcuts: Shortcuts::new(&parse_quote! { ::const_exhaustive }),
+        type_params: Vec::new(),
+    };
+    let eq = cx.eq(ty, &quote! { left }, &quote! { right }, 0)?;
+    Ok(quote! {
+        {
+            let (left, right): (&#ty, &#ty) = (#a, #b);
+            #eq
+        }
+    })
+}
+
+struct Context<'a> {
+    shortcuts: Shortcuts,
+    type_params: Vec<&'a Ident>,
+}
+
+fn binding(prefix: &str, fields: &Fields, index: usize) -> Ident {
+    match fields {
+        Fields::Named(fields) => {
+            let ident = fields.named[index]
+                .ident
+                .as_ref()
+                .expect("named field must have an ident");
+            Ident::new(&format!("{prefix}_{ident}"), Span::call_site())
+        }
+        Fields::Unnamed(_) | Fields::Unit => {
+            Ident::new(&format!("{prefix}_{index}"), Span::call_site())
+        }
+    }
+}
+
+// e.g. `Self::Variant { a: l_a, b: l_b }`
+fn pattern(fields: &Fields, construct: impl ToTokens, prefix: &str) -> TokenStream {
+    let bindings = (0..fields.len()).map(|index| binding(prefix, fields, index));
+    match fields {
+        Fields::Unit => quote! { #construct },
+        Fields::Unnamed(_) => quote! { #construct(#(#bindings),*) },
+        Fields::Named(named) => {
+            let idents = named.named.iter().map(|field| &field.ident);
+            quote! { #construct { #(#idents: #bindings),* } }
+        }
+    }
+}
+
+impl Context<'_> {
+    fn fields_eq(&self, fields: &Fields) -> Result<TokenStream> {
+        let eqs = fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let left = binding("l", fields, index).to_token_stream();
+                let right = binding("r", fields, index).to_token_stream();
+                match FieldAttrs::parse(field)?.const_eq {
+                    Some(const_eq) => Ok(quote! { #const_eq(#left, #right) }),
+                    None => self.eq(&field.ty, &left, &right, 0),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // wrapped in parentheses, so that a leading `match` or block isn't
+        // parsed as a statement
+        Ok(if eqs.is_empty() {
+            quote! { true }
+        } else {
+            quote! { (#(#eqs)&&*) }
+        })
+    }
+
+    // `a` and `b` are expressions of type `&#ty`
+    fn eq(&self, ty: &Type, a: &TokenStream, b: &TokenStream, depth: usize) -> Result<TokenStream> {
+        let Shortcuts { Option, Result, .. } = &self.shortcuts;
+
+        let eq = match ty {
+            Type::Paren(ty) => self.eq(&ty.elem, a, b, depth)?,
+            Type::Group(ty) => self.eq(&ty.elem, a, b, depth)?,
+            Type::Tuple(ty) if ty.elems.is_empty() => trivial(a, b),
+            Type::Tuple(ty) => {
+                let eqs = ty
+                    .elems
+                    .iter()
+                    .enumerate()
+                    .map(|(index, elem)| {
+                        let index = Index::from(index);
+                        self.eq(
+                            elem,
+                            &quote! { &(#a).#index },
+                            &quote! { &(#b).#index },
+                            depth,
+                        )
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                quote! { (#(#eqs)&&*) }
+            }
+            Type::Array(ty) => {
+                let (a_n, b_n, i_n) = depth_idents(depth);
+                let eq = self.eq(
+                    &ty.elem,
+                    &quote! { &#a_n[#i_n] },
+                    &quote! { &#b_n[#i_n] },
+                    depth + 1,
+                )?;
+                quote! {
+                    {
+                        let (#a_n, #b_n) = (#a, #b);
+                        let mut #i_n = 0;
+                        let mut eq = true;
+                        while eq && #i_n < #a_n.len() {
+                            eq = #eq;
+                            #i_n += 1;
+                        }
+                        eq
+                    }
+                }
+            }
+            Type::Path(path) if path.qself.is_none() => {
+                let path = &path.path;
+                if let Some(ident) = path.get_ident() {
+                    if PRIMITIVES.iter().any(|primitive| ident == primitive) {
+                        return Ok(quote! { (*#a == *#b) });
+                    }
+                    if self.type_params.contains(&ident) {
+                        return Err(Error::new_spanned(
+                            ty,
+                            "values of a type parameter cannot be compared in a const context",
+                        ));
+                    }
+                }
+
+                let last = path.segments.last().expect("path should not be empty");
+                let args = match &last.arguments {
+                    PathArguments::AngleBracketed(args) => args
+                        .args
+                        .iter()
+                        .filter_map(|arg| match arg {
+                            GenericArgument::Type(ty) => Some(ty),
+                            _ => None,
+                        })
+                        .collect::<Vec<_>>(),
+                    _ => Vec::new(),
+                };
+
+                let (a_n, b_n, _) = depth_idents(depth);
+                let name = if is_std(path, &last.ident) {
+                    last.ident.to_string()
+                } else {
+                    String::new()
+                };
+                match (name.as_str(), args.as_slice()) {
+                    ("PhantomData" | "PhantomPinned", _) => trivial(a, b),
+                    ("Infallible", []) => quote! { match *#a {} },
+                    ("Option", [t]) => {
+                        let eq =
+                            self.eq(t, &a_n.to_token_stream(), &b_n.to_token_stream(), depth + 1)?;
+                        quote! {
+                            match (#a, #b) {
+                                (#Option::Some(#a_n), #Option::Some(#b_n)) => #eq,
+                                (#Option::None, #Option::None) => true,
+                                _ => false,
+                            }
+                        }
+                    }
+                    ("Result", [t, e]) => {
+                        let a_n = a_n.to_token_stream();
+                        let b_n = b_n.to_token_stream();
+                        let eq_ok = self.eq(t, &a_n, &b_n, depth + 1)?;
+                        let eq_err = self.eq(e, &a_n, &b_n, depth + 1)?;
+                        quote! {
+                            match (#a, #b) {
+                                (#Result::Ok(#a_n), #Result::Ok(#b_n)) => #eq_ok,
+                                (#Result::Err(#a_n), #Result::Err(#b_n)) => #eq_err,
+                                _ => false,
+                            }
+                        }
+                    }
+                    _ => quote! { <#ty>::const_eq(#a, #b) },
+                }
+            }
+            _ => quote! { <#ty>::const_eq(#a, #b) },
+        };
+        Ok(eq)
+    }
+}
+
+// `path` is a std type, written either as its bare name like `Option`, or as
+// its full path like `::core::option::Option`
+fn is_std(path: &Path, name: &Ident) -> bool {
+    let module = match name.to_string().as_str() {
+        "Option" => "option",
+        "Result" => "result",
+        "PhantomData" | "PhantomPinned" => "marker",
+        "Infallible" => "convert",
+        _ => return false,
+    };
+    let segments = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>();
+    match segments.as_slice() {
+        [_] => path.leading_colon.is_none(),
+        [root, m, _] => (root == "core" || root == "std") && m == module,
+        _ => false,
+    }
+}
+
+// all values of this type are equal, but we still use the bindings to avoid
+// unused variable warnings
+fn trivial(a: &TokenStream, b: &TokenStream) -> TokenStream {
+    quote! {
+        {
+            let _ = (#a, #b);
+            true
+        }
+    }
+}
+
+fn depth_idents(depth: usize) -> (Ident, Ident, Ident) {
+    (
+        Ident::new(&format!("a_{depth}"), Span::call_site()),
+        Ident::new(&format!("b_{depth}"), Span::call_site()),
+        Ident::new(&format!("i_{depth}"), Span::call_site()),
+    )
+}
@@ -0,0 +1,268 @@
+use {
+    crate::{ContainerAttrs, FieldAttrs, FieldValues, Order, Shortcuts, array_len, range_len},
+    proc_macro2::{Span, TokenStream},
+    quote::{ToTokens, quote},
+    syn::{
+        Data, DeriveInput, Error, Field, Fields, Ident, Result, Type, WherePredicate, parse_quote,
+    },
+};
+
+// general description of how the macro works:
+// - this is a simpler version of `#[derive(Exhaustive)]`, since we don't build
+//   an `ALL` array, and `LEN` is a `usize` rather than a type-level number
+// - for each field set:
+//   - `LEN` is the product of the `LEN` of each field
+//   - we split `index` into the index of each field, in the same way as
+//     `Exhaustive::from_index`, then create each field's value from its index
+//   - fields with `values = ..`, `fixed = ..` or `with = ..` are handled in the
+//     same way as in `#[derive(Exhaustive)]`, except that values are created at
+//     runtime, so they don't need to be constant
+// - for enums, `LEN` is the sum of the `LEN` of each field set, and the index
+//   of a value is offset by the `LEN` of all variants before it
+
+pub fn derive(input: &DeriveInput) -> Result<TokenStream> {
+    let ContainerAttrs {
+        krate,
+        bound,
+        remote,
+        order,
+    } = ContainerAttrs::parse(input)?;
+    if let Some(remote) = remote {
+        return Err(Error::new_spanned(
+            remote,
+            "`remote` is not supported by `#[derive(Enumerable)]`",
+        ));
+    }
+    if matches!(order, Order::Gray | Order::Colex) {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "only `order = \"reverse\"` is supported by `#[derive(Enumerable)]`",
+        ));
+    }
+
+    let shortcuts = Shortcuts::new(&krate);
+    let Shortcuts {
+        Enumerable, Option, ..
+    } = &shortcuts;
+
+    let (len, nth, bounds) = match &input.data {
+        Data::Struct(data) => {
+            let FieldsImpl { len, nth, bounds } =
+                make_for_fields(&shortcuts, &data.fields, quote! { Self })?;
+            let nth = quote! {
+                if index >= #len {
+                    return #Option::None;
+                }
+                #nth
+            };
+            (len, nth, bounds)
+        }
+        Data::Enum(data) => {
+            let variants = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+                    make_for_fields(&shortcuts, &variant.fields, quote! { Self::#ident })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            let lens = variants.iter().map(|FieldsImpl { len, .. }| len);
+            let len = quote! { 0usize #(+ #lens)* };
+
+            let nth = variants
+                .iter()
+                .enumerate()
+                .map(|(i, FieldsImpl { len, nth, .. })| {
+                    let next = if i + 1 < variants.len() {
+                        quote! { let index = index - #len; }
+                    } else {
+                        quote! {}
+                    };
+                    quote! {
+                        if index < #len {
+                            return #nth;
+                        }
+                        #next
+                    }
+                })
+                .collect::<Vec<_>>();
+            let nth = quote! {
+                // in case there are no variants
+                let _ = index;
+                #(#nth)*
+                #Option::None
+            };
+
+            let bounds = variants
+                .into_iter()
+                .flat_map(|FieldsImpl { bounds, .. }| bounds)
+                .collect();
+            (len, nth, bounds)
+        }
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                input,
+                "enumerable union is not supported",
+            ));
+        }
+    };
+
+    // same as `#[derive(Exhaustive)]`, an empty enum has nothing to reverse
+    let is_empty = matches!(&input.data, Data::Enum(data) if data.variants.is_empty());
+    let nth = if matches!(order, Order::Reverse) && !is_empty {
+        quote! {
+            if index >= <Self as #Enumerable>::LEN {
+                return #Option::None;
+            }
+            let index = <Self as #Enumerable>::LEN - 1 - index;
+            #nth
+        }
+    } else {
+        nth
+    };
+
+    let mut generics = input.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .extend(bound.unwrap_or(bounds));
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #Enumerable for #name #type_generics #where_clause {
+            const LEN: usize = #len;
+
+            fn nth(index: usize) -> #Option<Self> {
+                #nth
+            }
+        }
+    })
+}
+
+struct FieldsImpl {
+    len: TokenStream,
+    nth: TokenStream,
+    bounds: Vec<WherePredicate>,
+}
+
+struct FieldInfo<'a> {
+    field: &'a Field,
+    attrs: FieldAttrs,
+    index: Ident,
+    ty: &'a Type,
+}
+
+fn make_for_fields(
+    shortcuts: &Shortcuts,
+    fields: &Fields,
+    construct_ident: impl ToTokens,
+) -> Result<FieldsImpl> {
+    let Shortcuts {
+        Enumerable,
+        ExhaustiveVia,
+        Unsigned,
+        Option,
+        split_index,
+        ..
+    } = shortcuts;
+
+    let infos = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let suffix = field
+                .ident
+                .as_ref()
+                .map_or_else(|| index.to_string(), ToString::to_string);
+            Ok(FieldInfo {
+                field,
+                attrs: FieldAttrs::parse(field)?,
+                index: Ident::new(&format!("i_{suffix}"), Span::call_site()),
+                ty: &field.ty,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // number of values of this field
+    let get_len = |FieldInfo { attrs, ty, .. }: &FieldInfo| match &attrs.values {
+        FieldValues::Exhaustive => quote! { <#ty as #Enumerable>::LEN },
+        FieldValues::With(with) => {
+            quote! { <<#with as #ExhaustiveVia<#ty>>::Num as #Unsigned>::USIZE }
+        }
+        FieldValues::Range {
+            start,
+            end,
+            inclusive,
+        } => range_len(ty, start, end, *inclusive),
+        FieldValues::Array(array) => array_len(array),
+        FieldValues::Fixed(_) => quote! { 1usize },
+    };
+    let get_value = |FieldInfo {
+                         attrs, ty, index, ..
+                     }: &FieldInfo| match &attrs.values {
+        FieldValues::Exhaustive => quote! { <#ty as #Enumerable>::nth(#index)? },
+        FieldValues::With(with) => {
+            quote! { <#with as #ExhaustiveVia<#ty>>::from_index(#index)? }
+        }
+        FieldValues::Range { start, .. } => {
+            quote! { ((#start) as i128 + #index as i128) as #ty }
+        }
+        FieldValues::Array(array) => quote! { (#array)[#index] },
+        FieldValues::Fixed(value) => quote! {
+            {
+                let _ = #index;
+                #value
+            }
+        },
+    };
+
+    // builds the fields part of a constructor, in the same way as
+    // `#[derive(Exhaustive)]`
+    let construct = match fields {
+        Fields::Unit => quote! {},
+        Fields::Unnamed(_) => {
+            let items = infos.iter().map(get_value);
+            quote! { ( #(#items),* ) }
+        }
+        Fields::Named(_) => {
+            let items = infos.iter().map(|info| {
+                let ident = &info.field.ident;
+                let item = get_value(info);
+                quote! { #ident: #item }
+            });
+            quote! { { #(#items),* } }
+        }
+    };
+
+    let lens = infos.iter().map(get_len).collect::<Vec<_>>();
+    let len = quote! { (1usize #(* #lens)*) };
+
+    // the caller must ensure that `index` is in bounds
+    let indices = infos.iter().map(|FieldInfo { index, .. }| index);
+    let nth = quote! {
+        {
+            let [#(#indices),*] = #split_index(index, [#(#lens),*]);
+            #Option::Some(#construct_ident #construct)
+        }
+    };
+
+    let bounds = infos
+        .iter()
+        .flat_map(|FieldInfo { attrs, ty, .. }| {
+            attrs.bound.clone().unwrap_or_else(|| match &attrs.values {
+                FieldValues::Exhaustive => vec![parse_quote! {
+                    #ty: #Enumerable
+                }],
+                FieldValues::With(with) => vec![parse_quote! {
+                    #with: #ExhaustiveVia<#ty>
+                }],
+                _ => Vec::new(),
+            })
+        })
+        .collect();
+
+    Ok(FieldsImpl { len, nth, bounds })
+}
@@ -0,0 +1,132 @@
+use {
+    crate::{Shortcuts, parse_path_or_str},
+    proc_macro2::{Span, TokenStream},
+    quote::{ToTokens, quote},
+    syn::{Error, FnArg, Ident, ItemFn, Pat, Path, Result, ReturnType, parse::Parser, parse_quote},
+};
+
+// general description of how the macro works:
+// - the function is kept as-is, but nested inside a new `#[test]` function with
+//   the same name and attributes
+// - the test iterates over `Exhaustive::iter` of a tuple of all argument types,
+//   and calls the inner function with each value
+//   - this unranks each value with `from_index` instead of copying out of
+//     `ALL`, so that `ALL` is never evaluated for types with many values
+// - each call is wrapped in `catch_unwind`, so that if it panics, we can panic
+//   again with a message which names the input, followed by the original
+//   message
+
+pub fn expand(args: TokenStream, item: ItemFn) -> Result<TokenStream> {
+    let mut krate = None;
+    syn::meta::parser(|meta| {
+        if meta.path.is_ident("crate") {
+            if krate.is_some() {
+                return Err(meta.error("duplicate `crate` attribute"));
+            }
+            krate = Some(parse_path_or_str::<Path>(&meta)?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported attribute"))
+        }
+    })
+    .parse2(args)?;
+    let krate = krate.unwrap_or_else(|| parse_quote! { ::const_exhaustive });
+
+    let Shortcuts {
+        Exhaustive,
+        Option,
+        Result,
+        String,
+        catch_unwind,
+        AssertUnwindSafe,
+        panic,
+        ..
+    } = &Shortcuts::new(&krate);
+
+    let ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = item;
+    if let Some(asyncness) = &sig.asyncness {
+        return Err(Error::new_spanned(
+            asyncness,
+            "`#[exhaustive_test]` function cannot be `async`",
+        ));
+    }
+    if !sig.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &sig.generics,
+            "`#[exhaustive_test]` function cannot have generic parameters",
+        ));
+    }
+    if let ReturnType::Type(_, ty) = &sig.output {
+        return Err(Error::new_spanned(
+            ty,
+            "`#[exhaustive_test]` function cannot return a value",
+        ));
+    }
+
+    let inputs = sig
+        .inputs
+        .iter()
+        .map(|input| match input {
+            FnArg::Typed(input) => Ok(input),
+            FnArg::Receiver(receiver) => Err(Error::new_spanned(
+                receiver,
+                "`#[exhaustive_test]` function cannot take `self`",
+            )),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let tys = inputs.iter().map(|input| &input.ty).collect::<Vec<_>>();
+    let bindings = (0..inputs.len())
+        .map(|index| Ident::new(&format!("v_{index}"), Span::call_site()))
+        .collect::<Vec<_>>();
+
+    // e.g. `failed on input {}: a = {:?}, b = {:?}`
+    // (braces in a pattern like `Point { x, y }` are escaped, since this is a
+    // format string)
+    let names = inputs
+        .iter()
+        .map(|input| match &*input.pat {
+            Pat::Ident(pat) => format!("{} = {{:?}}", pat.ident),
+            pat => format!(
+                "{} = {{:?}}",
+                pat.to_token_stream()
+                    .to_string()
+                    .replace('{', "{{")
+                    .replace('}', "}}")
+            ),
+        })
+        .collect::<Vec<_>>();
+    let format = format!("failed on input {{}}: {}\n{{}}", names.join(", "));
+
+    let ident = &sig.ident;
+    Ok(quote! {
+        #[test]
+        #(#attrs)*
+        #vis fn #ident() {
+            #sig #block
+
+            // take this before any locals can shadow the function's name
+            let test: fn(#(#tys),*) = #ident;
+
+            for (index, value) in <(#(#tys,)*) as #Exhaustive>::iter().enumerate() {
+                let (#(#bindings,)*) = value;
+                let result = #catch_unwind(#AssertUnwindSafe(|| test(#(#bindings),*)));
+                if let #Result::Err(payload) = result {
+                    let message = if let #Option::Some(message) = payload.downcast_ref::<&str>() {
+                        #String::from(*message)
+                    } else if let #Option::Some(message) = payload.downcast_ref::<#String>() {
+                        message.clone()
+                    } else {
+                        #String::from("Box<dyn Any>")
+                    };
+                    #panic!(#format, index, #(#bindings,)* message);
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,386 @@
+use {
+    crate::{ContainerAttrs, FieldAttrs, FieldValues, Order, Shortcuts, array_len, range_len},
+    proc_macro2::{Span, TokenStream},
+    quote::{ToTokens, quote},
+    syn::{
+        Data, DeriveInput, Error, Field, Fields, Ident, Result, Type, WherePredicate, parse_quote,
+    },
+};
+
+// general description of how the macro works:
+// - this is a simpler version of `#[derive(Exhaustive)]`, since we don't build
+//   an `ALL` array, and `COUNT` is a `u128` rather than a type-level number
+// - for each field set:
+//   - `COUNT` is the product of the `COUNT` of each field
+//   - the rank of a value is a mixed-radix number, where each field is a digit
+//     and the first field is the most significant, in the same way as
+//     `Exhaustive::index`
+//   - fields with `values = ..`, `fixed = ..` or `with = ..` are handled in the
+//     same way as in `#[derive(Exhaustive)]`, but their index is converted to a
+//     `u128`
+// - for enums, `COUNT` is the sum of the `COUNT` of each field set, and the
+//   rank of a value is offset by the `COUNT` of all variants before it
+
+pub fn derive(input: &DeriveInput) -> Result<TokenStream> {
+    let ContainerAttrs {
+        krate,
+        bound,
+        remote,
+        order,
+    } = ContainerAttrs::parse(input)?;
+    if let Some(remote) = remote {
+        return Err(Error::new_spanned(
+            remote,
+            "`remote` is not supported by `#[derive(Finite)]`",
+        ));
+    }
+    if matches!(order, Order::Gray | Order::Colex) {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "only `order = \"reverse\"` is supported by `#[derive(Finite)]`",
+        ));
+    }
+
+    let shortcuts = Shortcuts::new(&krate);
+    let Shortcuts {
+        Finite,
+        Option,
+        Sized,
+        Copy,
+        ..
+    } = &shortcuts;
+
+    let FiniteImpl {
+        count,
+        rank,
+        unrank,
+        bounds,
+    } = match &input.data {
+        Data::Struct(data) => {
+            let FieldsImpl {
+                count,
+                pattern,
+                rank,
+                unrank,
+                bounds,
+            } = make_for_fields(&shortcuts, &data.fields, quote! { Self })?;
+            FiniteImpl {
+                rank: quote! {
+                    let #pattern = value;
+                    #rank
+                },
+                unrank: quote! {
+                    if rank >= #count {
+                        return #Option::None;
+                    }
+                    #unrank
+                },
+                count,
+                bounds,
+            }
+        }
+        Data::Enum(data) => {
+            let variants = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+                    make_for_fields(&shortcuts, &variant.fields, quote! { Self::#ident })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            let counts = variants.iter().map(|FieldsImpl { count, .. }| count);
+            let count = quote! { 0u128 #(+ #counts)* };
+
+            // the rank of a variant's value is offset by the number of values
+            // in all variants before it
+            let rank = variants
+                .iter()
+                .scan(Vec::new(), |offset, variant| {
+                    let FieldsImpl {
+                        count,
+                        pattern,
+                        rank,
+                        ..
+                    } = variant;
+                    let arm = quote! {
+                        #pattern => 0u128 #(+ #offset)* + #rank
+                    };
+                    offset.push(count.clone());
+                    Some(arm)
+                })
+                .collect::<Vec<_>>();
+            let rank = quote! {
+                match value {
+                    #(#rank,)*
+                }
+            };
+
+            let unrank = variants
+                .iter()
+                .enumerate()
+                .map(|(i, variant)| {
+                    let FieldsImpl { count, unrank, .. } = variant;
+                    let next = if i + 1 < variants.len() {
+                        quote! { let rank = rank - #count; }
+                    } else {
+                        quote! {}
+                    };
+                    quote! {
+                        if rank < #count {
+                            return #unrank;
+                        }
+                        #next
+                    }
+                })
+                .collect::<Vec<_>>();
+            let unrank = quote! {
+                // in case there are no variants
+                let _ = rank;
+                #(#unrank)*
+                #Option::None
+            };
+
+            let bounds = variants
+                .into_iter()
+                .flat_map(|FieldsImpl { bounds, .. }| bounds)
+                .collect();
+            FiniteImpl {
+                count,
+                rank,
+                unrank,
+                bounds,
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new_spanned(input, "finite union is not supported"));
+        }
+    };
+
+    let mut generics = input.generics.clone();
+    generics.make_where_clause().predicates.push(parse_quote! {
+        // same bounds as `Finite`
+        Self: #Sized + #Copy
+    });
+    generics
+        .make_where_clause()
+        .predicates
+        .extend(bound.unwrap_or(bounds));
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    // same as `#[derive(Exhaustive)]`, an empty enum has nothing to reverse
+    let is_empty = matches!(&input.data, Data::Enum(data) if data.variants.is_empty());
+    let (rank, unrank) = if matches!(order, Order::Reverse) && !is_empty {
+        (
+            quote! { Self::COUNT - 1 - { #rank } },
+            quote! {
+                if rank >= Self::COUNT {
+                    return #Option::None;
+                }
+                let rank = Self::COUNT - 1 - rank;
+                #unrank
+            },
+        )
+    } else {
+        (rank, unrank)
+    };
+
+    Ok(quote! {
+        unsafe impl #impl_generics #Finite for #name #type_generics #where_clause {
+            const COUNT: u128 = #count;
+
+            fn rank(self) -> u128 {
+                let value = self;
+                #rank
+            }
+
+            fn unrank(rank: u128) -> #Option<Self> {
+                #unrank
+            }
+        }
+    })
+}
+
+struct FiniteImpl {
+    count: TokenStream,
+    rank: TokenStream,
+    unrank: TokenStream,
+    bounds: Vec<WherePredicate>,
+}
+
+struct FieldsImpl {
+    count: TokenStream,
+    pattern: TokenStream,
+    rank: TokenStream,
+    unrank: TokenStream,
+    bounds: Vec<WherePredicate>,
+}
+
+struct FieldInfo<'a> {
+    field: &'a Field,
+    attrs: FieldAttrs,
+    rank: Ident,
+    binding: Ident,
+    ty: &'a Type,
+}
+
+fn make_for_fields(
+    shortcuts: &Shortcuts,
+    fields: &Fields,
+    construct_ident: impl ToTokens,
+) -> Result<FieldsImpl> {
+    let Shortcuts {
+        Finite,
+        ExhaustiveVia,
+        Unsigned,
+        Option,
+        count_product,
+        join_rank,
+        split_rank,
+        ..
+    } = shortcuts;
+
+    let infos = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let suffix = field
+                .ident
+                .as_ref()
+                .map_or_else(|| index.to_string(), ToString::to_string);
+            Ok(FieldInfo {
+                field,
+                attrs: FieldAttrs::parse(field)?,
+                rank: Ident::new(&format!("r_{suffix}"), Span::call_site()),
+                binding: Ident::new(&format!("v_{suffix}"), Span::call_site()),
+                ty: &field.ty,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // number of values of this field
+    let get_count = |FieldInfo { attrs, ty, .. }: &FieldInfo| match &attrs.values {
+        FieldValues::Exhaustive => quote! { <#ty as #Finite>::COUNT },
+        FieldValues::With(with) => {
+            quote! { (<<#with as #ExhaustiveVia<#ty>>::Num as #Unsigned>::USIZE as u128) }
+        }
+        FieldValues::Range {
+            start,
+            end,
+            inclusive,
+        } => {
+            let len = range_len(ty, start, end, *inclusive);
+            quote! { ((#len) as u128) }
+        }
+        FieldValues::Array(array) => {
+            let len = array_len(array);
+            quote! { ((#len) as u128) }
+        }
+        FieldValues::Fixed(_) => quote! { 1u128 },
+    };
+    let get_rank = |FieldInfo {
+                        attrs, ty, binding, ..
+                    }: &FieldInfo| match &attrs.values {
+        FieldValues::Exhaustive => quote! { <#ty as #Finite>::rank(#binding) },
+        FieldValues::With(with) => {
+            quote! { (<#with as #ExhaustiveVia<#ty>>::index(#binding) as u128) }
+        }
+        FieldValues::Range { start, .. } => {
+            quote! { ((#binding as i128 - (#start) as i128) as u128) }
+        }
+        FieldValues::Array(array) => quote! {
+            ((#array)
+                .iter()
+                .position(|value| *value == #binding)
+                .expect("field should only hold one of its `values`") as u128)
+        },
+        FieldValues::Fixed(_) => quote! {
+            {
+                let _ = #binding;
+                0u128
+            }
+        },
+    };
+    let get_unrank = |FieldInfo {
+                          attrs, ty, rank, ..
+                      }: &FieldInfo| match &attrs.values {
+        FieldValues::Exhaustive => quote! { <#ty as #Finite>::unrank(#rank)? },
+        FieldValues::With(with) => {
+            quote! { <#with as #ExhaustiveVia<#ty>>::from_index(#rank as usize)? }
+        }
+        FieldValues::Range { start, .. } => {
+            quote! { ((#start) as i128 + #rank as i128) as #ty }
+        }
+        FieldValues::Array(array) => quote! { (#array)[#rank as usize] },
+        FieldValues::Fixed(value) => quote! {
+            {
+                let _ = #rank;
+                #value
+            }
+        },
+    };
+
+    // builds the fields part of a pattern or constructor, in the same way as
+    // `#[derive(Exhaustive)]`
+    let make_fields = |f: &dyn Fn(&FieldInfo) -> TokenStream| match fields {
+        Fields::Unit => quote! {},
+        Fields::Unnamed(_) => {
+            let items = infos.iter().map(f);
+            quote! { ( #(#items),* ) }
+        }
+        Fields::Named(_) => {
+            let items = infos.iter().map(|info| {
+                let ident = &info.field.ident;
+                let item = f(info);
+                quote! { #ident: #item }
+            });
+            quote! { { #(#items),* } }
+        }
+    };
+
+    let counts = infos.iter().map(get_count).collect::<Vec<_>>();
+    let count = quote! { #count_product(&[#(#counts),*]) };
+
+    let pattern = make_fields(&|FieldInfo { binding, .. }| binding.to_token_stream());
+    let pattern = quote! { #construct_ident #pattern };
+
+    let ranks = infos.iter().map(get_rank);
+    let rank = quote! {
+        #join_rank([#(#ranks),*], [#(#counts),*])
+    };
+
+    // the caller must ensure that `rank` is in bounds
+    let field_ranks = infos.iter().map(|FieldInfo { rank, .. }| rank);
+    let construct = make_fields(&get_unrank);
+    let unrank = quote! {
+        {
+            let [#(#field_ranks),*] = #split_rank(rank, [#(#counts),*]);
+            #Option::Some(#construct_ident #construct)
+        }
+    };
+
+    let bounds = infos
+        .iter()
+        .flat_map(|FieldInfo { attrs, ty, .. }| {
+            attrs.bound.clone().unwrap_or_else(|| match &attrs.values {
+                FieldValues::Exhaustive => vec![parse_quote! {
+                    #ty: #Finite
+                }],
+                FieldValues::With(with) => vec![parse_quote! {
+                    #with: #ExhaustiveVia<#ty>
+                }],
+                _ => Vec::new(),
+            })
+        })
+        .collect();
+
+    Ok(FieldsImpl {
+        count: quote! { (#count) },
+        pattern,
+        rank,
+        unrank,
+        bounds,
+    })
+}
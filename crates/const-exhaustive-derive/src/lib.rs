@@ -2,22 +2,37 @@
 //!
 //! [`const-exhaustive`]: https://docs.rs/const-exhaustive
 
+mod const_eq;
+mod enumerable;
+mod exhaustive_test;
+mod finite;
+
 use {
     core::iter::once,
     proc_macro2::{Span, TokenStream},
     quote::{ToTokens, quote},
     syn::{
-        Data, DataEnum, DataStruct, DeriveInput, Error, Field, Fields, Ident, Result, Type,
-        WherePredicate, parse_macro_input, parse_quote,
+        Data, DataEnum, DataStruct, DeriveInput, Error, Expr, ExprLit, ExprRange, Field, Fields,
+        Ident, ItemFn, Lit, LitStr, Path, RangeLimits, Result, Token, Type, WherePredicate,
+        meta::ParseNestedMeta, parse::Parse, parse_macro_input, parse_quote,
+        punctuated::Punctuated,
     },
 };
 
 /// Derives `const_exhaustive::Exhaustive` on this type.
 ///
 /// This type must be [`Clone`] and [`Copy`], and all types contained within
-/// it must also be `Exhaustive`.
+/// it must also be `Exhaustive`, unless a field attribute says otherwise.
+///
+/// The type may have type parameters.
 ///
-/// The type may have type parameters (see *Limitations*).
+/// On a struct, this also derives `const_exhaustive::ExhaustiveProduct`, unless
+/// the struct uses a non-default `order`.
+///
+/// This also derives `const_exhaustive::Finite`, which ranks values in the same
+/// order as `Exhaustive::ALL`, and `const_exhaustive::Samples`, whose samples
+/// are `Exhaustive::ALL`, so a type which derives `Exhaustive` must not also
+/// derive `Finite` or `Samples`.
 ///
 /// Be warned that if a type is `Exhaustive`, then changing any of its fields
 /// becomes a semver hazard.
@@ -26,39 +41,475 @@ use {
 ///
 /// This macro cannot be used on `union`s.
 ///
-/// This macro cannot be used on generic `enum`s whose first variants do not
-/// use a type parameter. This is a bug in the Rust compiler not propagating
-/// type bounds properly. Here's a minimal reproduction of the issue:
+/// # Attributes
+///
+/// ## `#[exhaustive(crate = path)]`
+///
+/// Sets the path to the `const_exhaustive` crate, which is used for all items
+/// that the generated code refers to. By default, this is `::const_exhaustive`.
+///
+/// Use this if the crate is only reachable through a re-export. The path may
+/// also be given as a string literal:
 ///
 /// ```ignore
-/// use typenum::{U0, U1, U2};
-/// use generic_array::ArrayLength;
-/// use core::ops::Add;
+/// #[derive(Clone, Copy, Exhaustive)]
+/// #[exhaustive(crate = "my_prelude::const_exhaustive")]
+/// struct MyStruct(bool);
+/// ```
 ///
-/// trait Exhaustive {
-///     type Num;
+/// ## `#[exhaustive(remote = path)]`
+///
+/// Derives `const_exhaustive::ExhaustiveVia<Remote>` instead of `Exhaustive`,
+/// where `Remote` is the type at the given path. Use this for types from
+/// another crate, which can't implement `Exhaustive` because of the orphan
+/// rules.
+///
+/// The type being derived on must be a copy of the definition of the remote
+/// type, with the same fields and variants. Values are constructed and matched
+/// using the remote type's own fields and variants, so if the copy doesn't
+/// match the remote type, the derive fails to compile. Since the copy is never
+/// constructed, you may need to allow `dead_code` on it.
+///
+/// The derived impl can be used with `#[exhaustive(with = ..)]` on a field, or
+/// with `const_exhaustive::Via` wherever an `Exhaustive` bound is required.
+/// As with `crate`, the path may also be given as a string literal.
+///
+/// ```ignore
+/// #[derive(Exhaustive)]
+/// #[exhaustive(remote = other_crate::Mode)]
+/// enum ModeDef {
+///     Fast,
+///     Slow { verbose: bool },
 /// }
 ///
-/// impl Exhaustive for bool {
-///     type Num = U2;
+/// type Mode = Via<other_crate::Mode, ModeDef>;
+/// ```
+///
+/// ## `#[exhaustive(order = "...")]`
+///
+/// Changes the order of values in `Exhaustive::ALL`, from the default "binary
+/// counting" order, to one of:
+/// - `"gray"`: reflected Gray code order, where each value differs from the
+///   previous one in exactly one field
+/// - `"colex"`: colexicographic order, where the last field is the most
+///   significant, rather than the first
+/// - `"reverse"`: the default order, but reversed
+///
+/// For enums, `"gray"` and `"colex"` apply to the fields of each variant, and
+/// variants are still ordered as they are written.
+///
+/// ```ignore
+/// #[derive(Clone, Copy, Exhaustive)]
+/// #[exhaustive(order = "gray")]
+/// struct Switches {
+///     a: bool,
+///     b: bool,
+///     c: bool,
 /// }
+/// ```
+///
+/// ## `#[exhaustive(bound = "...")]`
+///
+/// By default, the generated impl requires every field type to be
+/// `Exhaustive`, e.g. a field of type `Option<T>` adds `Option<T>: Exhaustive`
+/// to the where-clause. This attribute replaces those inferred predicates with
+/// the given ones, which may be empty.
 ///
-/// struct X<T>(T);
-/// impl<T> Exhaustive for X<T>
-/// where
-///     T: Exhaustive,
-///     U0: Add<U1, Output: Add<<T as Exhaustive>::Num, Output: ArrayLength>>, // broken
-///     // U0: Add<U1, Output: ArrayLength> // works
-///     // U0: Add<U1, Output: Add<<T as Exhaustive>::Num>>, // works
-///     // U0: Add<U1, Output: Add<<bool as Exhaustive>::Num, Output: ArrayLength>>, // works
-/// {
-///     type Num = U0;
+/// On a container, this replaces the predicates for all fields. On a field,
+/// this replaces the predicates for only that field.
+///
+/// Predicates which bound the `Num` of the type are always generated, since
+/// they are required for the impl to compile.
+///
+/// ```ignore
+/// #[derive(Clone, Copy, Exhaustive)]
+/// #[exhaustive(bound = "T: Exhaustive")]
+/// struct Wrapper<T> {
+///     value: T,
+/// }
+///
+/// #[derive(Clone, Copy, Exhaustive)]
+/// struct Tagged<T, Tag> {
+///     value: T,
+///     #[exhaustive(bound = "Tag: Exhaustive + Default")]
+///     tag: Tag,
 /// }
 /// ```
-#[proc_macro_derive(Exhaustive)]
+///
+/// ## `#[exhaustive(unsafe(values = ..))]`
+///
+/// Restricts a field to the given values, so that its type doesn't need to be
+/// `Exhaustive`. The values may be given as:
+/// - a range of integers, e.g. `0..4` or `1..=8`
+/// - an array, or a path to a constant array, e.g. `[1, 2, 4, 8]`
+///
+/// The values of this field are ordered in the same way as they are written.
+/// A range must not be empty, and an array must not contain duplicates - both
+/// are checked at compile time, so the values of an array must be primitives
+/// which can be compared in a const context. The values of a range must also
+/// fit in an `i128`, which only excludes `u128` values above `i128::MAX`.
+/// Ranges of `char`s aren't supported, since an integer can't be cast to a
+/// `char` - use an array instead.
+///
+/// # Safety
+///
+/// The derived impl is only correct if the field never holds any other value,
+/// so you must make sure of this yourself - for example, by making the field
+/// private and checking the value in a constructor. This is why the attribute
+/// must be wrapped in `unsafe(..)`.
+///
+/// ```ignore
+/// #[derive(Clone, Copy, Exhaustive)]
+/// pub struct Cell {
+///     #[exhaustive(unsafe(values = 0..4))]
+///     x: u8,
+///     #[exhaustive(unsafe(values = [1, 2, 4, 8]))]
+///     weight: u16,
+/// }
+/// ```
+///
+/// ## `#[exhaustive(unsafe(fixed = expr))]`
+///
+/// Treats a field as only ever having a single value, given by a constant
+/// expression, so that its type doesn't need to be `Exhaustive`. This is
+/// useful for fields like a `&'static str` tag or a version number.
+///
+/// # Safety
+///
+/// As with `values`, the derived impl is only correct if the field never holds
+/// any other value, so the attribute must be wrapped in `unsafe(..)`.
+///
+/// ```ignore
+/// #[derive(Clone, Copy, Exhaustive)]
+/// pub struct Message {
+///     #[exhaustive(unsafe(fixed = "message"))]
+///     tag: &'static str,
+///     #[exhaustive(unsafe(fixed = 2))]
+///     version: u32,
+///     urgent: bool,
+/// }
+/// ```
+///
+/// ## `#[exhaustive(with = path)]`
+///
+/// Reads the values of a field from a type implementing
+/// `const_exhaustive::ExhaustiveVia<T>`, where `T` is the field type, instead
+/// of from `<T as Exhaustive>::ALL`. This lets you derive on types with fields
+/// of a foreign type, which can't implement `Exhaustive` because of the orphan
+/// rules. As with `crate`, the path may also be given as a string literal.
+///
+/// ```ignore
+/// #[derive(Exhaustive)]
+/// #[exhaustive(remote = other_crate::Mode)]
+/// enum ModeDef {
+///     Fast,
+///     Slow { verbose: bool },
+/// }
+///
+/// #[derive(Clone, Copy, Exhaustive)]
+/// struct Config {
+///     #[exhaustive(with = ModeDef)]
+///     mode: other_crate::Mode,
+///     verbose: bool,
+/// }
+/// ```
+#[proc_macro_derive(Exhaustive, attributes(exhaustive))]
 pub fn exhaustive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
-    derive(&input)
+    derive(&input, Kind::Exhaustive)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Derives `const_exhaustive::Samples` on this type.
+///
+/// This generates values in the same way as `#[derive(Exhaustive)]`, except
+/// that the values of each field are taken from `Samples::SAMPLES` instead of
+/// `Exhaustive::ALL`. This means that fields may be of a type like `u32` or
+/// `f32`, which only has a set of representative values, rather than being
+/// `Exhaustive`.
+///
+/// This type must be [`Clone`] and [`Copy`], and all types contained within
+/// it must be `Samples`, unless a field attribute says otherwise.
+/// `#[derive(Exhaustive)]` already implements `Samples`, so only derive this on
+/// types which can't be `Exhaustive`.
+///
+/// ```ignore
+/// #[derive(Clone, Copy, Samples)]
+/// struct Reading {
+///     value: f32,
+///     sensor: u8,
+///     valid: bool,
+/// }
+/// ```
+///
+/// # Limitations
+///
+/// This macro cannot be used on `union`s.
+///
+/// # Attributes
+///
+/// This macro reads the same `#[exhaustive(..)]` attributes as
+/// `#[derive(Exhaustive)]`, which behave in the same way, except that `bound`
+/// replaces the inferred `Samples` predicates instead of `Exhaustive` ones.
+///
+/// `remote` is not supported.
+#[proc_macro_derive(Samples, attributes(exhaustive))]
+pub fn samples(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive(&input, Kind::Samples)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Derives `const_exhaustive::Finite` on this type.
+///
+/// This type must be [`Clone`] and [`Copy`], and all types contained within
+/// it must also be `Finite`, unless a field attribute says otherwise.
+///
+/// Values are ranked in the same order as `#[derive(Exhaustive)]` orders them
+/// in `Exhaustive::ALL`. Unlike `#[derive(Exhaustive)]`, this works for types
+/// with more values than could fit in `Exhaustive::ALL`, as long as the number
+/// of values fits in a `u128`.
+///
+/// `#[derive(Exhaustive)]` already implements `Finite`, so only derive this on
+/// types which aren't `Exhaustive`.
+///
+/// ```ignore
+/// #[derive(Clone, Copy, Exhaustive)]
+/// enum Direction {
+///     North,
+///     East,
+///     South,
+///     West,
+/// }
+///
+/// #[derive(Clone, Copy, Finite)]
+/// struct Board {
+///     cells: [[Option<Direction>; 6]; 6],
+///     turn: bool,
+/// }
+/// ```
+///
+/// # Limitations
+///
+/// This macro cannot be used on `union`s.
+///
+/// # Attributes
+///
+/// This macro reads the same `#[exhaustive(..)]` attributes as
+/// `#[derive(Exhaustive)]`. `crate`, `bound`, `values`, `fixed` and `with`
+/// behave in the same way, except that `bound` replaces the inferred `Finite`
+/// predicates instead of `Exhaustive` ones.
+///
+/// `remote` is not supported, and `order` only supports `"reverse"`.
+#[proc_macro_derive(Finite, attributes(exhaustive))]
+pub fn finite(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    finite::derive(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Derives `const_exhaustive::Enumerable` on this type.
+///
+/// Unlike `#[derive(Exhaustive)]`, this type doesn't need to be [`Copy`], so it
+/// may hold e.g. a `String`, or implement [`Drop`]. All types contained within
+/// it must be `Enumerable`, unless a field attribute says otherwise.
+///
+/// Every `Exhaustive` type is already `Enumerable`, so a type which derives
+/// `Exhaustive` must not also derive `Enumerable`.
+///
+/// Values are ordered in the same way as `#[derive(Exhaustive)]` orders them
+/// in `Exhaustive::ALL`.
+///
+/// ```ignore
+/// #[derive(Enumerable)]
+/// struct Alarm {
+///     #[exhaustive(unsafe(fixed = String::from("alarm")))]
+///     name: String,
+///     level: Level,
+///     enabled: bool,
+/// }
+///
+/// impl Drop for Alarm {
+///     fn drop(&mut self) {
+///         // ..
+///     }
+/// }
+/// ```
+///
+/// # Limitations
+///
+/// This macro cannot be used on `union`s.
+///
+/// # Attributes
+///
+/// This macro reads the same `#[exhaustive(..)]` attributes as
+/// `#[derive(Exhaustive)]`. `crate`, `bound`, `values`, `fixed` and `with`
+/// behave in the same way, except that:
+/// - `bound` replaces the inferred `Enumerable` predicates instead of
+///   `Exhaustive` ones
+/// - values given by `fixed` are created each time a value is requested, so
+///   they don't need to be constant
+///
+/// `remote` is not supported, and `order` only supports `"reverse"`.
+#[proc_macro_derive(Enumerable, attributes(exhaustive))]
+pub fn enumerable(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    enumerable::derive(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Derives an inherent `const fn const_eq(&self, other: &Self) -> bool` on
+/// this type, which checks if two values are equal in a const context.
+///
+/// [`PartialEq::eq`] cannot be called in a const context, so this macro looks
+/// at how the type of each field is written, and compares it accordingly:
+/// - primitives, such as `bool`, `u8` and `f32`, are compared with `==`
+/// - tuples, arrays, `Option` and `Result` are compared element-by-element
+/// - `PhantomData`, `PhantomPinned` and `Infallible` are trivially equal
+/// - any other type must have its own inherent `const_eq` - for example, by
+///   deriving `ConstEq` on it
+///
+/// `Option`, `Result`, `PhantomData`, `PhantomPinned` and `Infallible` are
+/// only recognized when written as their bare name, or as their full path in
+/// `core` or `std`, such as `core::option::Option`. Any other path, such as
+/// `io::Result<T>`, is treated as a type with its own `const_eq`.
+///
+/// This means that fields whose type is a type parameter are not supported,
+/// since we don't know how to compare them. Neither are type aliases of
+/// primitives, such as `type Flag = bool`, or your own types which are named
+/// the same as one of the types above - use `const_eq` on these fields.
+///
+/// # Attributes
+///
+/// ## `#[exhaustive(crate = path)]`
+///
+/// Same as on `#[derive(Exhaustive)]`.
+///
+/// ## `#[exhaustive(const_eq = path)]`
+///
+/// Compares a field using the given function, which must be a
+/// `const fn(&T, &T) -> bool`, where `T` is the field type. This can be used
+/// together with [`const_eq!`]:
+///
+/// ```ignore
+/// type Flag = bool;
+///
+/// const fn flag_eq(a: &Flag, b: &Flag) -> bool {
+///     const_eq!(bool, a, b)
+/// }
+///
+/// #[derive(Clone, Copy, ConstEq)]
+/// struct Flags {
+///     #[exhaustive(const_eq = flag_eq)]
+///     verbose: Flag,
+/// }
+/// ```
+///
+/// # Examples
+///
+/// This is typically used together with `Exhaustive`, to search
+/// `Exhaustive::ALL` at compile time:
+///
+/// ```ignore
+/// #[derive(Clone, Copy, Exhaustive, ConstEq)]
+/// enum Color {
+///     Red,
+///     Green,
+///     Blue(bool),
+/// }
+///
+/// const fn position(color: Color) -> usize {
+///     let mut i = 0;
+///     while !Color::ALL.as_slice()[i].const_eq(&color) {
+///         i += 1;
+///     }
+///     i
+/// }
+///
+/// const _: () = assert!(position(Color::Blue(true)) == 3);
+/// ```
+#[proc_macro_derive(ConstEq, attributes(exhaustive))]
+pub fn derive_const_eq(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    const_eq::derive(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Checks if two values of a type are equal in a const context.
+///
+/// This takes the type, followed by two expressions of type `&T`, and compares
+/// them in the same way as [`ConstEq`] compares a field of that type. Use this
+/// for types which you can't derive [`ConstEq`] on, such as `bool`, `Option`,
+/// `Result`, tuples and arrays.
+///
+/// ```ignore
+/// const fn is_origin(point: Option<(i32, i32)>) -> bool {
+///     const_eq!(Option<(i32, i32)>, &point, &Some((0, 0)))
+/// }
+///
+/// const _: () = assert!(is_origin(Some((0, 0))));
+/// const _: () = assert!(!is_origin(None));
+/// ```
+#[proc_macro]
+pub fn const_eq(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as const_eq::Input);
+    const_eq::expand(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Runs a test function once for every value of its arguments.
+///
+/// The function may take any number of arguments, and the tuple of all their
+/// types must be `const_exhaustive::Exhaustive`. This expands to a `#[test]`
+/// which iterates over `Exhaustive::iter` of that tuple, and calls the function
+/// with each value. `Exhaustive::ALL` is never evaluated, so the arguments may
+/// have more values than could be stored in a constant. The arguments must
+/// also be [`Debug`], so that if the
+/// function panics, the test fails with a message naming the input which
+/// failed, followed by the original panic message:
+///
+/// ```text
+/// failed on input 7: d = West, flip = true
+/// assertion `left == right` failed
+/// ```
+///
+/// Other attributes on the function, such as `#[ignore]` or `#[should_panic]`,
+/// are kept on the generated test.
+///
+/// ```ignore
+/// #[exhaustive_test]
+/// fn rotate_roundtrips(d: Direction, flip: bool) {
+///     let d = if flip { d.flip() } else { d };
+///     assert_eq!(d, d.rotate_cw().rotate_ccw());
+/// }
+/// ```
+///
+/// # Limitations
+///
+/// The function cannot be `async`, have generic parameters, take `self`, or
+/// return a value.
+///
+/// # Attributes
+///
+/// ## `#[exhaustive_test(crate = path)]`
+///
+/// Sets the path to the `const_exhaustive` crate, in the same way as
+/// `#[exhaustive(crate = path)]` on `#[derive(Exhaustive)]`.
+///
+/// [`Debug`]: core::fmt::Debug
+#[proc_macro_attribute]
+pub fn exhaustive_test(
+    args: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let item = parse_macro_input!(item as ItemFn);
+    exhaustive_test::expand(args.into(), item)
         .unwrap_or_else(|err| err.to_compile_error())
         .into()
 }
@@ -67,7 +518,7 @@ macro_rules! shortcuts {
     {
         struct $shortcuts_name:ident {
             $(
-                $($item_path:ident::)* : $item_name:ident
+                $root:ident::$($item_path:ident::)* : $item_name:ident
             ),* $(,)?
         }
     } => {
@@ -78,11 +529,14 @@ macro_rules! shortcuts {
             )*
         }
 
-        impl Default for $shortcuts_name {
-            fn default() -> Self {
+        impl $shortcuts_name {
+            fn new(krate: &Path) -> Self {
                 Self {
                     $(
-                        $item_name: quote! { ::$($item_path::)*$item_name },
+                        $item_name: {
+                            let root = shortcut_root!($root, krate);
+                            quote! { #root::$($item_path::)*$item_name }
+                        },
                     )*
                 }
             }
@@ -90,77 +544,446 @@ macro_rules! shortcuts {
     };
 }
 
+// `const_exhaustive` items go through the user-provided crate path, since the
+// crate may only be reachable through a re-export
+macro_rules! shortcut_root {
+    (core, $krate:expr) => {
+        quote! { ::core }
+    };
+    (std, $krate:expr) => {
+        quote! { ::std }
+    };
+    (const_exhaustive, $krate:expr) => {
+        $krate.to_token_stream()
+    };
+}
+
 shortcuts! {
     struct Shortcuts {
         core::marker:::Sized,
         core::marker:::Copy,
         core::mem:::MaybeUninit,
-        core::ops:::Add,
-        core::ops:::Mul,
+        core::convert:::TryFrom,
+        core::option:::Option,
+        core::result:::Result,
+        std::string:::String,
+        std::panic:::catch_unwind,
+        std::panic:::AssertUnwindSafe,
+        std:::panic,
+        const_exhaustive:::Enumerable,
         const_exhaustive:::Exhaustive,
+        const_exhaustive:::ExhaustiveProduct,
+        const_exhaustive:::ExhaustiveVia,
+        const_exhaustive:::Finite,
+        const_exhaustive:::Samples,
+        const_exhaustive:::FieldLen,
+        const_exhaustive:::FieldSamples,
+        const_exhaustive:::FieldVia,
+        const_exhaustive:::FieldsNum,
+        const_exhaustive:::VariantsNum,
         const_exhaustive:::const_transmute,
-        const_exhaustive::typenum:::U0,
-        const_exhaustive::typenum:::U1,
+        const_exhaustive:::join_index,
+        const_exhaustive:::split_index,
+        const_exhaustive:::lex_to_gray,
+        const_exhaustive:::gray_to_lex,
+        const_exhaustive:::lex_to_colex,
+        const_exhaustive:::colex_to_lex,
+        const_exhaustive:::count_of,
+        const_exhaustive:::count_product,
+        const_exhaustive:::join_rank,
+        const_exhaustive:::split_rank,
+        const_exhaustive::typenum:::ATerm,
+        const_exhaustive::typenum:::TArr,
         const_exhaustive::typenum:::Unsigned,
         const_exhaustive::generic_array:::GenericArray,
         const_exhaustive::generic_array:::ArrayLength,
     }
 }
 
+// which trait `derive` implements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Exhaustive,
+    Samples,
+}
+
+struct ContainerAttrs {
+    krate: Path,
+    bound: Option<Vec<WherePredicate>>,
+    remote: Option<Path>,
+    order: Order,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Order {
+    Lex,
+    Reverse,
+    Gray,
+    Colex,
+}
+
+impl ContainerAttrs {
+    fn parse(input: &DeriveInput) -> Result<Self> {
+        let mut krate = None;
+        let mut bound = None;
+        let mut remote = None;
+        let mut order = None;
+        for attr in &input.attrs {
+            if !attr.path().is_ident("exhaustive") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("crate") {
+                    if krate.is_some() {
+                        return Err(meta.error("duplicate `crate` attribute"));
+                    }
+                    krate = Some(parse_path_or_str(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("bound") {
+                    if bound.is_some() {
+                        return Err(meta.error("duplicate `bound` attribute"));
+                    }
+                    bound = Some(parse_bound(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("remote") {
+                    if remote.is_some() {
+                        return Err(meta.error("duplicate `remote` attribute"));
+                    }
+                    remote = Some(parse_path_or_str(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("order") {
+                    if order.is_some() {
+                        return Err(meta.error("duplicate `order` attribute"));
+                    }
+                    let value = meta.value()?.parse::<LitStr>()?;
+                    order = Some(match value.value().as_str() {
+                        "gray" => Order::Gray,
+                        "reverse" => Order::Reverse,
+                        "colex" => Order::Colex,
+                        _ => {
+                            return Err(Error::new_spanned(
+                                value,
+                                "expected one of `gray`, `reverse` or `colex`",
+                            ));
+                        }
+                    });
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported attribute"))
+                }
+            })?;
+        }
+
+        Ok(Self {
+            krate: krate.unwrap_or_else(|| parse_quote! { ::const_exhaustive }),
+            bound,
+            remote,
+            order: order.unwrap_or(Order::Lex),
+        })
+    }
+}
+
+struct FieldAttrs {
+    bound: Option<Vec<WherePredicate>>,
+    values: FieldValues,
+    // only used by `#[derive(ConstEq)]`
+    const_eq: Option<Path>,
+}
+
+enum FieldValues {
+    // all values of the field type, which must be `Exhaustive`
+    Exhaustive,
+    Range {
+        start: Box<Expr>,
+        end: Box<Expr>,
+        inclusive: bool,
+    },
+    Array(Expr),
+    Fixed(Expr),
+    With(Type),
+}
+
+impl FieldAttrs {
+    fn parse(field: &Field) -> Result<Self> {
+        let mut bound = None;
+        let mut values = None;
+        let mut const_eq = None;
+        for attr in &field.attrs {
+            if !attr.path().is_ident("exhaustive") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("bound") {
+                    if bound.is_some() {
+                        return Err(meta.error("duplicate `bound` attribute"));
+                    }
+                    bound = Some(parse_bound(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("unsafe") {
+                    meta.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("values") {
+                            if values.is_some() {
+                                return Err(meta.error(VALUES_CONFLICT));
+                            }
+                            values = Some(parse_values(&meta)?);
+                            Ok(())
+                        } else if meta.path.is_ident("fixed") {
+                            if values.is_some() {
+                                return Err(meta.error(VALUES_CONFLICT));
+                            }
+                            values = Some(FieldValues::Fixed(meta.value()?.parse()?));
+                            Ok(())
+                        } else {
+                            Err(meta.error("unsupported unsafe attribute"))
+                        }
+                    })
+                } else if meta.path.is_ident("values") || meta.path.is_ident("fixed") {
+                    Err(meta.error(
+                        "`values` and `fixed` must be wrapped in `unsafe(..)`, since the derived \
+                         impl is only correct if the field never holds any other value",
+                    ))
+                } else if meta.path.is_ident("with") {
+                    if values.is_some() {
+                        return Err(meta.error(VALUES_CONFLICT));
+                    }
+                    values = Some(FieldValues::With(parse_path_or_str(&meta)?));
+                    Ok(())
+                } else if meta.path.is_ident("const_eq") {
+                    if const_eq.is_some() {
+                        return Err(meta.error("duplicate `const_eq` attribute"));
+                    }
+                    const_eq = Some(parse_path_or_str(&meta)?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported attribute"))
+                }
+            })?;
+        }
+
+        Ok(Self {
+            bound,
+            values: values.unwrap_or(FieldValues::Exhaustive),
+            const_eq,
+        })
+    }
+}
+
+const VALUES_CONFLICT: &str = "only one of `values`, `fixed` or `with` may be set";
+
+// e.g. `key = some::path` or `key = "some::path"`
+fn parse_path_or_str<T: Parse>(meta: &ParseNestedMeta) -> Result<T> {
+    let value = meta.value()?;
+    if value.peek(LitStr) {
+        value.parse::<LitStr>()?.parse()
+    } else {
+        value.parse()
+    }
+}
+
+fn parse_bound(meta: &ParseNestedMeta) -> Result<Vec<WherePredicate>> {
+    let bound = meta.value()?.parse::<LitStr>()?;
+    let predicates = bound.parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated)?;
+    Ok(predicates.into_iter().collect())
+}
+
+fn parse_values(meta: &ParseNestedMeta) -> Result<FieldValues> {
+    match meta.value()?.parse::<Expr>()? {
+        Expr::Range(ExprRange {
+            start: Some(start),
+            limits,
+            end: Some(end),
+            ..
+        }) => Ok(FieldValues::Range {
+            start,
+            end,
+            inclusive: matches!(limits, RangeLimits::Closed(_)),
+        }),
+        expr @ Expr::Range(_) => Err(Error::new_spanned(
+            expr,
+            "range of values must have a start and an end",
+        )),
+        expr => Ok(FieldValues::Array(expr)),
+    }
+    .and_then(|values| match &values {
+        FieldValues::Range { start, end, .. } => {
+            for bound in [start, end] {
+                if let Expr::Lit(ExprLit {
+                    lit: Lit::Char(_), ..
+                }) = &**bound
+                {
+                    return Err(Error::new_spanned(
+                        bound,
+                        "range of `char`s is not supported - use an array instead",
+                    ));
+                }
+            }
+            Ok(values)
+        }
+        _ => Ok(values),
+    })
+}
+
+// number of values in `values = start..end` of a field of type `ty`, as a
+// `usize` which is checked at compile time
+//
+// values are offset from `start` as `i128`s, so we also check that the range
+// fits in an `i128` - otherwise a `u128` range past `i128::MAX` would wrap
+fn range_len(ty: &Type, start: &Expr, end: &Expr, inclusive: bool) -> TokenStream {
+    let (cmp, extra) = if inclusive {
+        (quote! { <= }, quote! { + 1 })
+    } else {
+        (quote! { < }, quote! {})
+    };
+    quote! {
+        const {
+            #[allow(clippy::unnecessary_cast, reason = "bounds may be untyped literals")]
+            let (start, end) = ((#start) as #ty, (#end) as #ty);
+            ::core::assert!(start #cmp end, "range of values must not be empty");
+            #[allow(
+                unused_comparisons,
+                clippy::absurd_extreme_comparisons,
+                reason = "the field type may be unsigned"
+            )]
+            let fits = ((end as i128) < 0) == (end < (0 as #ty));
+            ::core::assert!(fits, "range of values must fit in an `i128`");
+            (end as i128 - start as i128 #extra) as usize
+        }
+    }
+}
+
+// number of values in `values = [..]`, as a `usize` which is checked at
+// compile time
+fn array_len(array: &Expr) -> TokenStream {
+    quote! {
+        const {
+            let values = &(#array);
+            let mut i = 0;
+            while i < values.len() {
+                let mut j = i + 1;
+                while j < values.len() {
+                    #[allow(clippy::float_cmp, reason = "values must be exactly distinct")]
+                    let distinct = values[i] != values[j];
+                    ::core::assert!(distinct, "values must not contain duplicates");
+                    j += 1;
+                }
+                i += 1;
+            }
+            values.len()
+        }
+    }
+}
+
 // general description of how the macro works:
 // - works on a set of fields
 //   - this could be the `{ .. }` in `SomeStruct { .. }`
 //   - or the `{ .. }` in `SomeEnum::Variant { .. }`
 // - make a `all` array, which will be the result of `Exhaustive::ALL`
 // - make a `i`, which is the current index into `all` that we're writing
-// - for each field:
+// - for each field set:
 //   - figure out `Num`
-//     - make a `typenum` type which represents how many values that field has
-//       - e.g. a `my_field: bool` has `<bool as Exhaustive>::Num` values
-//       - done by multiplying the `Num` of each field type together
-//       - e.g. in a `(bool, Option<bool>)` `Num` is `<bool::Num as Mul<
-//         <Option<bool>>::Num >>::Output`
-//     - write a where-clause predicate that expresses that the type we've made
-//       above is bounded by `ArrayLength<ArrayType<Self>: Copy>`
+//     - make a type-level list of the field types, e.g. for `(bool, T)` we make
+//       `TArr<bool, TArr<T, ATerm>>`
+//     - `Num` is then `<#list as FieldsNum>::Output`, which multiplies the
+//       `Num` of each field type together
+//     - write a where-clause predicate `#list: FieldsNum`
 //       - required when deriving on a type with type parameters
-//       - this is structured as `U1: #bound`
-//       - `#bound` starts off as `ArrayType<Self>: Copy`
-//       - for each field, we wrap the existing `#bound` in `Mul<#field_ty::Num,
-//         Output: #bound>`
+//       - see `FieldsNum` for why we don't bound on the `Num`s directly
+//     - a field with `values = ..` or `fixed = ..` isn't `Exhaustive`, so
+//       instead of the field type, it uses `FieldLen<{ number of values }>` in
+//       the list
+//     - a field with `with = ..` uses `FieldVia<#with, #ty>` in the list
+//   - write a where-clause predicate `#ty: Exhaustive` for each field
+//     - these are the "bounds", which the user may replace with `bound = ".."`
+//     - fields with `with = ..` get `#with: ExhaustiveVia<#ty>` instead
+//     - fields with `values = ..` or `fixed = ..` don't get a bound
 //   - figure out `ALL`
 //     - write all its values into `all`, and increase `i` accordingly
 //
 // - for structs, we take the resulting `Num` and `all`, and use those directly
+//   - we also bound `#list: FieldsNum<Output: ArrayLength<ArrayType<Self>:
+//     Copy>>`, since that's what `Exhaustive::Num` requires
 //
 // - for enums
-//   - we take all the type bounds we've made from the field sets, and add those
-//     to the `where` clause
-//   - we also compute a final where predicate, in the form of `U0: #bound`
-//     - starts off as `U0: ArrayLength<ArrayType<Self>: Copy>`
-//     - for each field, we wrap the existing `#bound` in `Add<#field_ty::Num,
-//       Output: #bound>`
-//   - take all those where predicates, plus our final predicate, put them in
-//     the impl block
+//   - we take all the field set lists, and make a list of those lists
+//   - `Num` is then `<#variants as VariantsNum>::Output`, which adds the `Num`
+//     of each field set together
+//   - take all the where predicates of the field sets, plus a final predicate
+//     `#variants: VariantsNum<Output: ArrayLength<ArrayType<Self>: Copy>>`, and
+//     put them in the impl block
 //   - take the resulting `Num` and `all`, and put those into `Num` and `ALL`
+//
+// - with `remote = ..`, everything above is the same, except that
+//   - values are constructed and matched using the remote path instead of
+//     `Self`, which checks that our fields match the remote type's fields
+//   - we implement `ExhaustiveVia<#remote>` on `Self`, instead of `Exhaustive`
+//
+// - `#[derive(Samples)]` also goes through here, except that
+//   - field values are taken from `Samples::SAMPLES` instead of
+//     `Exhaustive::ALL`, and fields are bounded on `Samples`
+//   - field types are wrapped in `FieldSamples<#ty>` in the `FieldsNum` list
+//   - we implement `Samples` with `SAMPLES`, and don't generate `index` or
+//     `from_index`, since a value may not be one of the samples
 
-fn derive(input: &DeriveInput) -> Result<TokenStream> {
+fn derive(input: &DeriveInput, kind: Kind) -> Result<TokenStream> {
+    let ContainerAttrs {
+        krate,
+        bound,
+        remote,
+        order,
+    } = ContainerAttrs::parse(input)?;
+    if let (Kind::Samples, Some(remote)) = (kind, &remote) {
+        return Err(Error::new_spanned(
+            remote,
+            "`remote` is not supported by `#[derive(Samples)]`",
+        ));
+    }
+    let shortcuts = Shortcuts::new(&krate);
     let Shortcuts {
         Exhaustive,
+        ExhaustiveProduct,
+        ExhaustiveVia,
+        Finite,
+        Samples,
+        Unsigned,
         MaybeUninit,
         GenericArray,
         Sized,
         Copy,
+        TryFrom,
         const_transmute,
+        count_of,
+        Option,
         ..
-    } = Shortcuts::default();
+    } = &shortcuts;
+
+    let name = &input.ident;
+    let target = remote.as_ref().map_or_else(
+        || Target {
+            ty: quote! { Self },
+            path: quote! { Self },
+        },
+        |remote| {
+            let (_, type_generics, _) = input.generics.split_for_impl();
+            Target {
+                ty: quote! { #remote #type_generics },
+                path: remote.to_token_stream(),
+            }
+        },
+    );
 
     let ExhaustiveImpl {
         num,
         values,
+        index,
+        from_index,
+        radices,
+        bounds,
         predicates,
     } = match &input.data {
-        Data::Struct(data) => make_for_struct(data),
-        Data::Enum(data) => make_for_enum(data),
+        Data::Struct(data) => make_for_struct(&shortcuts, &target, data, order, kind)?,
+        Data::Enum(data) => make_for_enum(&shortcuts, &target, data, order, kind)?,
         Data::Union(_) => {
             return Err(Error::new_spanned(
                 input,
@@ -169,93 +992,273 @@ fn derive(input: &DeriveInput) -> Result<TokenStream> {
         }
     };
 
-    let name = &input.ident;
-
+    let Target { ty, .. } = &target;
     let mut generics = input.generics.clone();
     generics.make_where_clause().predicates.push(parse_quote! {
         // same bounds as `Exhaustive`
-        Self: #Sized + #Copy
+        #ty: #Sized + #Copy
     });
+    generics
+        .make_where_clause()
+        .predicates
+        .extend(bound.unwrap_or(bounds));
     generics.make_where_clause().predicates.extend(predicates);
 
     let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
 
-    Ok(quote! {
-        unsafe impl #impl_generics #Exhaustive for #name #type_generics #where_clause {
-            type Num = #num;
-
-            const ALL: #GenericArray<Self, Self::Num> = {
-                let mut all: #GenericArray<#MaybeUninit<Self>, Self::Num> = unsafe {
-                    #MaybeUninit::uninit().assume_init()
-                };
-
-                let mut i = 0;
+    // unlike the other orders, this applies to the whole type rather than to
+    // each field set, so we reverse the values after they have been written
+    // (an empty enum has nothing to reverse, and its `index` never returns)
+    let is_empty = matches!(&input.data, Data::Enum(data) if data.variants.is_empty());
+    let (values, index, from_index) = if matches!(order, Order::Reverse) && !is_empty {
+        let len = quote! { <#num as #Unsigned>::USIZE };
+        (
+            quote! {
                 #values
 
-                unsafe { #const_transmute(all) }
+                let mut lo = 0usize;
+                let mut hi = #len;
+                while lo + 1 < hi {
+                    hi -= 1;
+                    all.as_mut_slice().swap(lo, hi);
+                    lo += 1;
+                }
+            },
+            quote! { #len - 1 - { #index } },
+            quote! {
+                if index >= #len {
+                    return #Option::None;
+                }
+                let index = #len - 1 - index;
+                #from_index
+            },
+        )
+    } else {
+        (values, index, from_index)
+    };
+
+    let all = quote! {
+        {
+            let mut all: #GenericArray<#MaybeUninit<#ty>, #num> = unsafe {
+                #MaybeUninit::uninit().assume_init()
             };
+
+            let mut i = 0;
+            #values
+
+            unsafe { #const_transmute(all) }
+        }
+    };
+
+    Ok(if kind == Kind::Samples {
+        quote! {
+            impl #impl_generics #Samples for #name #type_generics #where_clause {
+                type Num = #num;
+
+                const SAMPLES: #GenericArray<Self, Self::Num> = #all;
+            }
+        }
+    } else if remote.is_some() {
+        quote! {
+            unsafe impl #impl_generics #ExhaustiveVia<#ty> for #name #type_generics #where_clause {
+                type Num = #num;
+
+                const ALL: #GenericArray<#ty, #num> = #all;
+
+                fn index(value: #ty) -> usize {
+                    #index
+                }
+
+                fn from_index(index: usize) -> #Option<#ty> {
+                    #from_index
+                }
+            }
+        }
+    } else {
+        // only structs in the default order count through their fields in the
+        // way that `ExhaustiveProduct` requires
+        let product = radices
+            .filter(|_| matches!(order, Order::Lex))
+            .map(|radices| {
+                quote! {
+                    unsafe impl #impl_generics #ExhaustiveProduct for #name #type_generics #where_clause {
+                        const RADICES: &'static [usize] = &#radices;
+                    }
+                }
+            });
+
+        quote! {
+            unsafe impl #impl_generics #Exhaustive for #name #type_generics #where_clause {
+                type Num = #num;
+
+                const ALL: #GenericArray<Self, Self::Num> = #all;
+
+                fn index(self) -> usize {
+                    let value = self;
+                    #index
+                }
+
+                fn from_index(index: usize) -> #Option<Self> {
+                    #from_index
+                }
+            }
+
+            #product
+
+            unsafe impl #impl_generics #Finite for #name #type_generics #where_clause {
+                const COUNT: u128 = #count_of::<Self>();
+
+                fn rank(self) -> u128 {
+                    <Self as #Exhaustive>::index(self) as u128
+                }
+
+                fn unrank(rank: u128) -> #Option<Self> {
+                    #TryFrom::try_from(rank)
+                        .ok()
+                        .and_then(<Self as #Exhaustive>::from_index)
+                }
+            }
+
+            impl #impl_generics #Samples for #name #type_generics #where_clause {
+                type Num = <Self as #Exhaustive>::Num;
+
+                const SAMPLES: #GenericArray<Self, Self::Num> = <Self as #Exhaustive>::ALL;
+            }
         }
     })
 }
 
+// type whose values we're enumerating
+struct Target {
+    // e.g. `Self` or `other::Remote<T>`
+    ty: TokenStream,
+    // used to construct and match on values, e.g. `Self` or `other::Remote`
+    path: TokenStream,
+}
+
 struct ExhaustiveImpl {
     num: TokenStream,
     values: TokenStream,
+    index: TokenStream,
+    from_index: TokenStream,
+    // number of values of each field, if this is a product of fields
+    radices: Option<TokenStream>,
+    bounds: Vec<WherePredicate>,
     predicates: Vec<WherePredicate>,
 }
 
-fn make_for_struct(data: &DataStruct) -> ExhaustiveImpl {
-    make_for_fields(&data.fields, quote! { Self })
+fn make_for_struct(
+    shortcuts: &Shortcuts,
+    target: &Target,
+    data: &DataStruct,
+    order: Order,
+    kind: Kind,
+) -> Result<ExhaustiveImpl> {
+    let Target { ty, path } = target;
+    let FieldsImpl {
+        list,
+        num,
+        lengths,
+        values,
+        pattern,
+        index,
+        from_index,
+        bounds,
+    } = make_for_fields(shortcuts, &data.fields, path, order, kind)?;
+
+    let Shortcuts {
+        FieldsNum,
+        ArrayLength,
+        Copy,
+        Unsigned,
+        Option,
+        ..
+    } = shortcuts;
+
+    let predicates = vec![parse_quote! {
+        #list: #FieldsNum<Output: #ArrayLength<ArrayType<#ty>: #Copy>>
+    }];
+
+    let index = quote! {
+        let #pattern = value;
+        #index
+    };
+    let from_index = quote! {
+        if index >= <#num as #Unsigned>::USIZE {
+            return #Option::None;
+        }
+        #from_index
+    };
+
+    Ok(ExhaustiveImpl {
+        num,
+        values,
+        index,
+        from_index,
+        radices: Some(lengths),
+        bounds,
+        predicates,
+    })
 }
 
-fn make_for_enum(data: &DataEnum) -> ExhaustiveImpl {
+fn make_for_enum(
+    shortcuts: &Shortcuts,
+    target: &Target,
+    data: &DataEnum,
+    order: Order,
+    kind: Kind,
+) -> Result<ExhaustiveImpl> {
+    let Target { ty, path } = target;
     let Shortcuts {
-        U0,
-        Add,
+        FieldsNum,
+        VariantsNum,
+        ATerm,
+        TArr,
         ArrayLength,
         Copy,
+        Unsigned,
+        Option,
         ..
-    } = Shortcuts::default();
+    } = shortcuts;
 
     let variants = data
         .variants
         .iter()
         .map(|variant| {
             let ident = &variant.ident;
-            make_for_fields(&variant.fields, quote! { Self::#ident })
+            make_for_fields(
+                shortcuts,
+                &variant.fields,
+                quote! { #path::#ident },
+                order,
+                kind,
+            )
         })
-        .collect::<Vec<_>>();
+        .collect::<Result<Vec<_>>>()?;
 
-    // note the order of folds below:
-    // - `Num` definition is left fold
-    // - `Num` bound is right fold, opposite of `Num` definition
-    // this is the opposite of what structs need, how interesting!
-    // I wonder why?
-
-    let num = variants
+    let list = variants
         .iter()
-        .fold(quote! { #U0 }, |acc, ExhaustiveImpl { num, .. }| {
-            quote! { <#acc as #Add<#num>>::Output }
+        .rfold(quote! { #ATerm }, |acc, FieldsImpl { list, .. }| {
+            quote! { #TArr<#list, #acc> }
         });
+    let num = quote! { <#list as #VariantsNum>::Output };
 
-    let bound = variants.iter().rfold(
-        quote! { #ArrayLength<ArrayType<Self>: #Copy> },
-        |acc, ExhaustiveImpl { num, .. }| {
-            quote! {
-                #Add<#num, Output: #acc>
-            }
-        },
-    );
-    let predicate = parse_quote! { #U0: #bound };
+    let predicate = parse_quote! {
+        #list: #VariantsNum<Output: #ArrayLength<ArrayType<#ty>: #Copy>>
+    };
+    let bounds = variants
+        .iter()
+        .flat_map(|e| e.bounds.clone())
+        .collect::<Vec<_>>();
     let predicates = variants
         .iter()
-        .flat_map(|e| e.predicates.clone())
+        .map(|FieldsImpl { list, .. }| parse_quote! { #list: #FieldsNum })
         .chain(once(predicate))
         .collect::<Vec<_>>();
 
     let values = variants
         .iter()
-        .map(|ExhaustiveImpl { values, .. }| {
+        .map(|FieldsImpl { values, .. }| {
             quote! {
                 {
                     #values
@@ -267,17 +1270,92 @@ fn make_for_enum(data: &DataEnum) -> ExhaustiveImpl {
         #(#values)*
     };
 
-    ExhaustiveImpl {
+    // the index of a variant's value is offset by the number of values in all
+    // variants before it
+    let index = variants
+        .iter()
+        .scan(Vec::new(), |offset, variant| {
+            let FieldsImpl {
+                num,
+                pattern,
+                index,
+                ..
+            } = variant;
+            let arm = quote! {
+                #pattern => 0usize #(+ #offset)* + #index
+            };
+            offset.push(quote! { <#num as #Unsigned>::USIZE });
+            Some(arm)
+        })
+        .collect::<Vec<_>>();
+    let index = quote! {
+        match value {
+            #(#index,)*
+        }
+    };
+
+    let from_index = variants
+        .iter()
+        .enumerate()
+        .map(|(i, variant)| {
+            let FieldsImpl {
+                num, from_index, ..
+            } = variant;
+            let len = quote! { <#num as #Unsigned>::USIZE };
+            let next = if i + 1 < variants.len() {
+                quote! { let index = index - #len; }
+            } else {
+                quote! {}
+            };
+            quote! {
+                if index < #len {
+                    return #from_index;
+                }
+                #next
+            }
+        })
+        .collect::<Vec<_>>();
+    let from_index = quote! {
+        // in case there are no variants
+        let _ = index;
+        #(#from_index)*
+        #Option::None
+    };
+
+    Ok(ExhaustiveImpl {
         num,
         values,
+        index,
+        from_index,
+        radices: None,
+        bounds,
         predicates,
-    }
+    })
 }
 
-fn make_for_fields(fields: &Fields, construct_ident: impl ToTokens) -> ExhaustiveImpl {
+struct FieldsImpl {
+    list: TokenStream,
+    num: TokenStream,
+    lengths: TokenStream,
+    values: TokenStream,
+    pattern: TokenStream,
+    index: TokenStream,
+    from_index: TokenStream,
+    bounds: Vec<WherePredicate>,
+}
+
+fn make_for_fields(
+    shortcuts: &Shortcuts,
+    fields: &Fields,
+    construct_ident: impl ToTokens,
+    order: Order,
+    kind: Kind,
+) -> Result<FieldsImpl> {
     struct FieldInfo<'a> {
         field: &'a Field,
+        attrs: FieldAttrs,
         index: Ident,
+        binding: Ident,
         ty: &'a Type,
     }
 
@@ -288,127 +1366,251 @@ fn make_for_fields(fields: &Fields, construct_ident: impl ToTokens) -> Exhaustiv
             .expect("named field must have an ident")
     }
 
-    fn get_value(ty: impl ToTokens, index: impl ToTokens) -> TokenStream {
-        let Shortcuts { Exhaustive, .. } = Shortcuts::default();
-
-        quote! {
-            <#ty as #Exhaustive>::ALL.as_slice()[#index]
-        }
-    }
-
     let Shortcuts {
         MaybeUninit,
         Exhaustive,
-        U1,
+        ExhaustiveVia,
+        Samples,
+        FieldLen,
+        FieldSamples,
+        FieldVia,
+        FieldsNum,
+        ATerm,
+        TArr,
         Unsigned,
-        Mul,
-        ArrayLength,
-        Copy,
+        Option,
+        join_index,
+        split_index,
+        lex_to_gray,
+        gray_to_lex,
+        lex_to_colex,
+        colex_to_lex,
         ..
-    } = Shortcuts::default();
-
-    let (fields, construct) = match fields {
-        Fields::Unit => (Vec::<FieldInfo>::new(), quote! {}),
-        Fields::Unnamed(fields) => {
-            let fields = fields
-                .unnamed
-                .iter()
-                .enumerate()
-                .map(|(index, field)| {
-                    let index = Ident::new(&format!("i_{index}"), Span::call_site());
-                    FieldInfo {
-                        field,
-                        index,
-                        ty: &field.ty,
-                    }
-                })
-                .collect::<Vec<_>>();
-            let construct = fields
-                .iter()
-                .map(|FieldInfo { field, index, .. }| get_value(&field.ty, index));
-            let construct = quote! {
-                (
-                    #(#construct),*
-                )
-            };
-            (fields, construct)
+    } = shortcuts;
+
+    // trait which field types without an attribute are bounded on, and the
+    // constant which holds their values
+    let (field_trait, field_values) = match kind {
+        Kind::Exhaustive => (Exhaustive, quote! { ALL }),
+        Kind::Samples => (Samples, quote! { SAMPLES }),
+    };
+
+    // for fields whose values come from a trait impl, the trait to get them
+    // from, e.g. `<#ty as Exhaustive>`
+    let get_source = |FieldInfo { attrs, ty, .. }: &FieldInfo| match &attrs.values {
+        FieldValues::Exhaustive => Some(quote! { <#ty as #field_trait> }),
+        FieldValues::With(with) => Some(quote! { <#with as #ExhaustiveVia<#ty>> }),
+        _ => None,
+    };
+    // number of values of this field
+    let get_len = |info: &FieldInfo| match &info.attrs.values {
+        FieldValues::Exhaustive | FieldValues::With(_) => {
+            let source = get_source(info);
+            quote! { <#source::Num as #Unsigned>::USIZE }
         }
-        Fields::Named(fields) => {
-            let fields = fields
-                .named
-                .iter()
-                .map(|field| {
-                    let ident = require_ident(field);
-                    let index = Ident::new(&format!("i_{ident}"), Span::call_site());
-                    FieldInfo {
-                        field,
-                        index,
-                        ty: &field.ty,
-                    }
-                })
-                .collect::<Vec<_>>();
-            let construct = fields
-                .iter()
-                .map(|FieldInfo { field, index, .. }| {
-                    let ident = require_ident(field);
-                    let get_value = get_value(&field.ty, index);
-                    quote! { #ident: #get_value }
-                })
-                .collect::<Vec<_>>();
-            let construct = quote! {
+        FieldValues::Range {
+            start,
+            end,
+            inclusive,
+        } => range_len(info.ty, start, end, *inclusive),
+        FieldValues::Array(array) => array_len(array),
+        FieldValues::Fixed(_) => quote! { 1usize },
+    };
+    // item in the `FieldsNum` list
+    let get_item = |info: &FieldInfo| match &info.attrs.values {
+        FieldValues::Exhaustive => {
+            let ty = info.ty;
+            match kind {
+                Kind::Exhaustive => ty.to_token_stream(),
+                Kind::Samples => quote! { #FieldSamples<#ty> },
+            }
+        }
+        FieldValues::With(with) => {
+            let ty = info.ty;
+            quote! { #FieldVia<#with, #ty> }
+        }
+        _ => {
+            let len = get_len(info);
+            quote! { #FieldLen<{ #len }> }
+        }
+    };
+    let get_value = |info: &FieldInfo| {
+        let FieldInfo {
+            attrs, ty, index, ..
+        } = info;
+        match &attrs.values {
+            FieldValues::Exhaustive => {
+                let source = get_source(info);
+                quote! { #source::#field_values.as_slice()[#index] }
+            }
+            FieldValues::With(_) => {
+                let source = get_source(info);
+                quote! { #source::ALL.as_slice()[#index] }
+            }
+            FieldValues::Range { start, .. } => {
+                quote! { ((#start) as i128 + #index as i128) as #ty }
+            }
+            FieldValues::Array(array) => quote! { (#array)[#index] },
+            FieldValues::Fixed(value) => quote! {
                 {
-                    #(#construct),*
+                    let _ = #index;
+                    #value
                 }
-            };
-            (fields, construct)
+            },
+        }
+    };
+    let get_from_index = |info: &FieldInfo| {
+        get_source(info).map_or_else(
+            || get_value(info),
+            |source| {
+                let index = &info.index;
+                quote! { #source::from_index(#index)? }
+            },
+        )
+    };
+    let get_index = |info: &FieldInfo| {
+        let FieldInfo { attrs, binding, .. } = info;
+        match &attrs.values {
+            FieldValues::Exhaustive | FieldValues::With(_) => {
+                let source = get_source(info);
+                quote! { #source::index(#binding) }
+            }
+            FieldValues::Range { start, .. } => {
+                quote! { (#binding as i128 - (#start) as i128) as usize }
+            }
+            FieldValues::Array(array) => quote! {
+                (#array)
+                    .iter()
+                    .position(|value| *value == #binding)
+                    .expect("field should only hold one of its `values`")
+            },
+            FieldValues::Fixed(_) => quote! {
+                {
+                    let _ = #binding;
+                    0usize
+                }
+            },
         }
     };
 
-    // see comment about order of folds above
+    let fields_kind = fields;
+    let fields = match fields {
+        Fields::Unit => Vec::<FieldInfo>::new(),
+        Fields::Unnamed(fields) => fields
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                Ok(FieldInfo {
+                    field,
+                    attrs: FieldAttrs::parse(field)?,
+                    index: Ident::new(&format!("i_{index}"), Span::call_site()),
+                    binding: Ident::new(&format!("v_{index}"), Span::call_site()),
+                    ty: &field.ty,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?,
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|field| {
+                let ident = require_ident(field);
+                Ok(FieldInfo {
+                    field,
+                    attrs: FieldAttrs::parse(field)?,
+                    index: Ident::new(&format!("i_{ident}"), Span::call_site()),
+                    binding: Ident::new(&format!("v_{ident}"), Span::call_site()),
+                    ty: &field.ty,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?,
+    };
 
-    let num = fields
-        .iter()
-        .rfold(quote! { #U1 }, |acc, FieldInfo { field, .. }| {
-            let ty = &field.ty;
+    // builds the fields part of a pattern or constructor, e.g.
+    // - `(#a, #b)` for tuple fields
+    // - `{ x: #a, y: #b }` for named fields
+    let make_fields = |f: &dyn Fn(&FieldInfo) -> TokenStream| match fields_kind {
+        Fields::Unit => quote! {},
+        Fields::Unnamed(_) => {
+            let items = fields.iter().map(f);
             quote! {
-                <#acc as #Mul<<#ty as #Exhaustive>::Num>>::Output
+                (
+                    #(#items),*
+                )
             }
-        });
-
-    let bound = fields.iter().fold(
-        quote! { #ArrayLength<ArrayType<Self>: #Copy> },
-        |acc, FieldInfo { field, .. }| {
-            let ty = &field.ty;
+        }
+        Fields::Named(_) => {
+            let items = fields.iter().map(|info| {
+                let ident = require_ident(info.field);
+                let item = f(info);
+                quote! { #ident: #item }
+            });
             quote! {
-                #Mul<<#ty as #Exhaustive>::Num, Output: #acc>
+                {
+                    #(#items),*
+                }
             }
-        },
-    );
-    let extra_predicate = parse_quote! { #U1: #bound };
+        }
+    };
 
-    let predicates = fields
+    let construct = make_fields(&get_value);
+
+    let list = fields.iter().rfold(quote! { #ATerm }, |acc, info| {
+        let item = get_item(info);
+        quote! { #TArr<#item, #acc> }
+    });
+    let num = quote! { <#list as #FieldsNum>::Output };
+
+    let bounds = fields
         .iter()
-        .map(|FieldInfo { ty, .. }| {
-            parse_quote! {
-                #ty: #Exhaustive<Num: #ArrayLength<ArrayType<Self>: #Copy>>
-            }
+        .flat_map(|FieldInfo { attrs, ty, .. }| {
+            attrs.bound.clone().unwrap_or_else(|| match &attrs.values {
+                FieldValues::Exhaustive => vec![parse_quote! {
+                    #ty: #field_trait
+                }],
+                FieldValues::With(with) => vec![parse_quote! {
+                    #with: #ExhaustiveVia<#ty>
+                }],
+                _ => Vec::new(),
+            })
         })
-        .chain(once(extra_predicate))
         .collect::<Vec<_>>();
 
+    // mixed-radix index, where the first field is the most significant digit
+    let lengths = fields.iter().map(get_len);
+    let lengths = quote! { [#(#lengths),*] };
+
+    // converts between the mixed-radix index above, and the position of the
+    // value in `order`
+    let (to_order, from_order) = match order {
+        Order::Lex | Order::Reverse => (None, None),
+        Order::Gray => (Some(lex_to_gray), Some(gray_to_lex)),
+        Order::Colex => (Some(lex_to_colex), Some(colex_to_lex)),
+    };
+
     // rfold here so that the value order matches the tuple value order
     // e.g. we generate i_0 { i_1 { i_2 } }
     //       instead of i_2 { i_1 { i_0 } }
+    let (start, position) = to_order.map_or_else(
+        || (quote! {}, quote! { i }),
+        |to_order| {
+            (
+                quote! { let start = i; },
+                quote! { start + #to_order(i - start, &#lengths) },
+            )
+        },
+    );
     let values = fields.iter().rfold(
         quote! {
-            all.as_mut_slice()[i] = #MaybeUninit::new(#construct_ident #construct);
+            all.as_mut_slice()[#position] = #MaybeUninit::new(#construct_ident #construct);
             i += 1;
         },
-        |acc, FieldInfo { field, index, .. }| {
-            let ty = &field.ty;
+        |acc, info| {
+            let index = &info.index;
+            let len = get_len(info);
             quote! {
                 let mut #index = 0usize;
-                while #index < <<#ty as #Exhaustive>::Num as #Unsigned>::USIZE {
+                while #index < #len {
                     #acc
                     #index += 1;
                 };
@@ -416,9 +1618,49 @@ fn make_for_fields(fields: &Fields, construct_ident: impl ToTokens) -> Exhaustiv
         },
     );
 
-    ExhaustiveImpl {
+    let values = quote! {
+        #start
+        #values
+    };
+
+    let pattern = make_fields(&|FieldInfo { binding, .. }| binding.to_token_stream());
+    let pattern = quote! { #construct_ident #pattern };
+
+    let field_indices = fields.iter().map(get_index);
+    let index = quote! {
+        #join_index([#(#field_indices),*], #lengths)
+    };
+    let index = match to_order {
+        Some(to_order) => quote! { #to_order(#index, &#lengths) },
+        None => index,
+    };
+
+    // the inverse of the above
+    // the caller must ensure that `index` is in bounds
+    let indices = fields.iter().map(|FieldInfo { index, .. }| index);
+    let lex_index = from_order.map_or_else(
+        || quote! { index },
+        |from_order| quote! { #from_order(index, &#lengths) },
+    );
+    let split = quote! {
+        let [#(#indices),*] = #split_index(#lex_index, #lengths);
+    };
+    let construct_from_index = make_fields(&get_from_index);
+    let from_index = quote! {
+        {
+            #split
+            #Option::Some(#construct_ident #construct_from_index)
+        }
+    };
+
+    Ok(FieldsImpl {
+        list,
         num,
+        lengths,
         values,
-        predicates,
-    }
+        pattern,
+        index,
+        from_index,
+        bounds,
+    })
 }
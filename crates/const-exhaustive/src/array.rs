@@ -7,9 +7,14 @@ use {
     typenum::Sum,
 };
 
-macro_rules! from_fn {
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __from_fn {
     ($len:ty, | $i:pat_param | $f:expr $(,)?) => {{
-        use {::core::mem::MaybeUninit, $crate::generic_array::const_transmute};
+        use {
+            ::core::mem::MaybeUninit,
+            $crate::{array::assume_init, generic_array::GenericArray},
+        };
 
         let mut dst: GenericArray<MaybeUninit<_>, $len> =
             unsafe { MaybeUninit::uninit().assume_init() };
@@ -22,15 +27,17 @@ macro_rules! from_fn {
             i += 1;
         }
 
-        unsafe { const_transmute(dst) }
+        unsafe { assume_init(dst) }
     }};
 }
 
-macro_rules! map {
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __map {
     ($src:expr, | $in:pat_param | $f:expr $(,)?) => {{
         use {
             ::core::mem::MaybeUninit,
-            $crate::{array::assert_same_length, generic_array::const_transmute},
+            $crate::array::{assert_same_length, assume_init},
         };
 
         let mut dst = assert_same_length::<_, MaybeUninit<_>, _>(&$src, unsafe {
@@ -45,11 +52,24 @@ macro_rules! map {
             i += 1;
         }
 
-        unsafe { const_transmute(dst) }
+        unsafe { assume_init(dst) }
     }};
 }
 
-pub(crate) use {from_fn, map};
+pub(crate) use {__from_fn as from_fn, __map as map};
+
+// takes the element type from `src`, rather than letting the caller pick any
+// output type, like `const_transmute` would
+//
+// # Safety
+//
+// all elements of `src` must be initialized
+#[must_use]
+pub const unsafe fn assume_init<T, N: ArrayLength>(
+    src: GenericArray<MaybeUninit<T>, N>,
+) -> GenericArray<T, N> {
+    unsafe { generic_array::const_transmute(src) }
+}
 
 pub const fn assert_same_length<A, B, N: ArrayLength>(
     _: &GenericArray<A, N>,
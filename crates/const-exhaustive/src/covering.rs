@@ -0,0 +1,202 @@
+use {
+    crate::ExhaustiveProduct,
+    alloc::{vec, vec::Vec},
+    typenum::Unsigned,
+};
+
+/// Creates a small set of values of `T` which covers every combination of
+/// values of every pair of fields of `T`.
+///
+/// This is [`t_wise`] with a strength of 2. See [`t_wise`] for details.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// use const_exhaustive::{Exhaustive, pairwise};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+/// enum Browser {
+///     Firefox,
+///     Chrome,
+///     Safari,
+/// }
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+/// struct Config {
+///     browser: Browser,
+///     dark_mode: bool,
+///     logged_in: bool,
+///     mobile: bool,
+/// }
+///
+/// let configs = pairwise::<Config>();
+/// // instead of all 24 values
+/// assert!(configs.len() < 24);
+/// for browser in Browser::ALL {
+///     for mobile in bool::ALL {
+///         assert!(
+///             configs
+///                 .iter()
+///                 .any(|config| config.browser == browser && config.mobile == mobile)
+///         );
+///     }
+/// }
+/// ```
+#[must_use]
+pub fn pairwise<T: ExhaustiveProduct>() -> Vec<T> {
+    t_wise::<T, 2>()
+}
+
+/// Creates a small set of values of `T` which covers every combination of
+/// values of every `STRENGTH` fields of `T`.
+///
+/// For types with many fields, testing against all [`Exhaustive::ALL`] values
+/// quickly becomes too slow, since the number of values is the product of the
+/// number of values of each field. Most bugs are triggered by an interaction
+/// between only a few fields, so a covering array of strength 2 or 3 keeps most
+/// of the value of an exhaustive test, while its size grows much more slowly.
+///
+/// The fields of `T` are described by [`ExhaustiveProduct::RADICES`], which is
+/// implemented for tuples, arrays, and structs which derive [`Exhaustive`].
+///
+/// Values are chosen greedily, so the result is not necessarily the smallest
+/// possible covering array, but it is deterministic. If `STRENGTH` is at least
+/// the number of fields, this returns all values of `T`.
+///
+/// This never evaluates [`Exhaustive::ALL`], so it also works for types which
+/// have too many values to store in a constant, such as `[bool; 20]`.
+///
+/// Requires the `alloc` feature.
+///
+/// [`Exhaustive`]: crate::Exhaustive
+/// [`Exhaustive::ALL`]: crate::Exhaustive::ALL
+///
+/// # Examples
+///
+/// ```
+/// use const_exhaustive::{Exhaustive, t_wise};
+///
+/// type Flags = [bool; 10];
+///
+/// let flags = t_wise::<Flags, 3>();
+/// // instead of all 1024 values
+/// assert!(flags.len() < 32);
+/// for (a, b, c) in <(bool, bool, bool)>::ALL {
+///     assert!(
+///         flags
+///             .iter()
+///             .any(|flags| flags[2] == a && flags[5] == b && flags[9] == c)
+///     );
+/// }
+/// ```
+#[must_use]
+pub fn t_wise<T: ExhaustiveProduct, const STRENGTH: usize>() -> Vec<T> {
+    let radices = T::RADICES;
+    // values are created with `from_index` rather than read from `ALL`, so
+    // that we don't evaluate `ALL` for types with many values
+    if STRENGTH >= radices.len() || radices.contains(&0) {
+        return (0..T::Num::USIZE).filter_map(T::from_index).collect();
+    }
+
+    // every set of `STRENGTH` fields, and the start of each set's region in
+    // `covered`, which has one entry for each combination of the set's values
+    let combinations = combinations::<STRENGTH>(radices.len());
+    let mut offsets = Vec::with_capacity(combinations.len());
+    let mut total = 0usize;
+    for fields in &combinations {
+        offsets.push(total);
+        total += fields
+            .iter()
+            .map(|&field| radices[field])
+            .product::<usize>();
+    }
+    let mut covered = vec![false; total];
+
+    // the combinations which each field is part of
+    let mut containing = vec![Vec::new(); radices.len()];
+    for (combination, fields) in combinations.iter().enumerate() {
+        for &field in fields {
+            containing[field].push(combination);
+        }
+    }
+
+    // position of a combination of values in `covered`, or `None` if some
+    // of its fields haven't been assigned yet
+    let position = |combination: usize, row: &[Option<usize>]| {
+        let mut index = 0;
+        for &field in &combinations[combination] {
+            index = index * radices[field] + row[field]?;
+        }
+        Some(offsets[combination] + index)
+    };
+
+    let mut values = Vec::new();
+    let mut row = vec![None; radices.len()];
+    // start each row from the first combination of values which isn't covered
+    // yet, so that every row covers at least one more
+    while let Some(first) = covered.iter().position(|covered| !covered) {
+        row.fill(None);
+        let combination = offsets.partition_point(|&offset| offset <= first) - 1;
+        let mut index = first - offsets[combination];
+        for &field in combinations[combination].iter().rev() {
+            row[field] = Some(index % radices[field]);
+            index /= radices[field];
+        }
+
+        // then pick the value of each other field which covers the most new
+        // combinations, together with the fields assigned before it
+        for field in 0..radices.len() {
+            if row[field].is_some() {
+                continue;
+            }
+            let mut best = (0, 0);
+            for value in 0..radices[field] {
+                row[field] = Some(value);
+                let gain = containing[field]
+                    .iter()
+                    .filter_map(|&combination| position(combination, &row))
+                    .filter(|&position| !covered[position])
+                    .count();
+                if gain > best.1 {
+                    best = (value, gain);
+                }
+            }
+            row[field] = Some(best.0);
+        }
+
+        // all fields are assigned now
+        for combination in 0..combinations.len() {
+            if let Some(position) = position(combination, &row) {
+                covered[position] = true;
+            }
+        }
+        let index = row
+            .iter()
+            .flatten()
+            .zip(radices)
+            .fold(0, |index, (value, radix)| index * radix + value);
+        values.extend(T::from_index(index));
+    }
+    values
+}
+
+// all subsets of `STRENGTH` indices in `0..len`, in lexicographic order
+fn combinations<const STRENGTH: usize>(len: usize) -> Vec<[usize; STRENGTH]> {
+    let mut result = Vec::new();
+    let mut current: [usize; STRENGTH] = core::array::from_fn(|i| i);
+    loop {
+        result.push(current);
+        let Some(i) = (0..STRENGTH)
+            .rev()
+            .find(|&i| current[i] < len - STRENGTH + i)
+        else {
+            return result;
+        };
+        current[i] += 1;
+        for j in i + 1..STRENGTH {
+            current[j] = current[j - 1] + 1;
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use {crate::Exhaustive, typenum::Unsigned};
+
+/// Type which has a finite number of values, which can be created on demand.
+///
+/// This is a weaker form of [`Exhaustive`]. It has no `ALL` array, so it has no
+/// [`Copy`] bound, and can be implemented on types which hold e.g. a `String`
+/// or implement [`Drop`]. Instead, each value is constructed when it is
+/// requested with [`Enumerable::nth`].
+///
+/// This trait should be derived instead of implemented manually - see
+/// [`const_exhaustive_derive::Enumerable`].
+///
+/// This trait is implemented for every [`Exhaustive`] type, where
+/// [`Enumerable::LEN`] is [`Exhaustive::Num`], and [`Enumerable::nth`] is
+/// [`Exhaustive::from_index`]. Because of this, types like `Option<T>` or
+/// `[T; N]` only implement [`Enumerable`] when `T` is [`Exhaustive`].
+///
+/// # Examples
+///
+/// ```
+/// use const_exhaustive::{Enumerable, Exhaustive};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+/// enum Level {
+///     Low,
+///     High,
+/// }
+///
+/// #[derive(Debug, PartialEq, Enumerable)]
+/// struct Alarm {
+///     #[exhaustive(unsafe(fixed = String::from("alarm")))]
+///     name: String,
+///     level: Level,
+///     enabled: bool,
+/// }
+///
+/// assert_eq!(4, Alarm::LEN);
+/// assert_eq!(
+///     Some(Alarm {
+///         name: String::from("alarm"),
+///         level: Level::High,
+///         enabled: false,
+///     }),
+///     Alarm::nth(2),
+/// );
+/// assert_eq!(None, Alarm::nth(4));
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "the values of `{Self}` cannot be enumerated",
+    label = "not enumerable",
+    note = "consider annotating `{Self}` with `#[derive(Enumerable)]`"
+)]
+pub trait Enumerable: Sized {
+    /// Number of values that may exist of this type.
+    ///
+    /// This is named differently to [`Finite::COUNT`], so that both can be
+    /// used on a type which implements both traits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use const_exhaustive::Enumerable;
+    ///
+    /// assert_eq!(2, bool::LEN);
+    /// assert_eq!(3, Option::<bool>::LEN);
+    /// ```
+    ///
+    /// [`Finite::COUNT`]: crate::Finite::COUNT
+    const LEN: usize;
+
+    /// Creates the value at position `index` among all values of this type.
+    ///
+    /// Returns [`None`] if `index` is out of bounds, i.e. greater than or equal
+    /// to [`Enumerable::LEN`].
+    ///
+    /// Values are ordered in the same way as [`Exhaustive::ALL`], and
+    /// `#[derive(Enumerable)]` orders values in the same way as
+    /// `#[derive(Exhaustive)]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use const_exhaustive::Enumerable;
+    ///
+    /// assert_eq!(Some(None), Option::<bool>::nth(0));
+    /// assert_eq!(Some(Some(true)), Option::<bool>::nth(2));
+    /// assert_eq!(None, Option::<bool>::nth(3));
+    /// ```
+    #[must_use]
+    fn nth(index: usize) -> Option<Self>;
+}
+
+impl<T: Exhaustive> Enumerable for T {
+    const LEN: usize = T::Num::USIZE;
+
+    fn nth(index: usize) -> Option<Self> {
+        T::from_index(index)
+    }
+}
@@ -0,0 +1,397 @@
+use {
+    crate::Exhaustive,
+    core::{
+        cmp::{Ordering, Reverse},
+        convert::Infallible,
+        fmt::Alignment,
+        marker::{PhantomData, PhantomPinned},
+        mem::ManuallyDrop,
+        num::{FpCategory, Saturating, Wrapping},
+        ops::{Bound, ControlFlow},
+        task::Poll,
+    },
+    typenum::Unsigned,
+    variadics_please::all_tuples,
+};
+
+/// Type which has a finite number of values, which can be counted and ranked
+/// without enumerating all of them.
+///
+/// This is a weaker form of [`Exhaustive`]. It has no `ALL` array, and counts
+/// values with a [`u128`] instead of a [`typenum`](crate::typenum) number, so
+/// it can describe types with far more values than could ever be stored in
+/// memory, such as `[bool; 64]` or a struct of many fields. Use it to rank,
+/// unrank, or randomly sample a huge space of values, e.g. by calling
+/// [`Finite::unrank`] with a random number below [`Finite::COUNT`].
+///
+/// This trait should be derived instead of implemented manually - see
+/// [`const_exhaustive_derive::Finite`].
+///
+/// This trait is implemented for every type in this crate which implements
+/// [`Exhaustive`], and `#[derive(Exhaustive)]` implements it too. For these
+/// types, [`Finite::COUNT`] is the same as [`Exhaustive::Num`], and
+/// [`Finite::rank`] is the same as [`Exhaustive::index`], so values are ranked
+/// in the same order as in [`Exhaustive::ALL`]. `#[derive(Finite)]` ranks
+/// values in the same order that `#[derive(Exhaustive)]` would.
+///
+/// This trait isn't implemented for all [`Exhaustive`] types, since that would
+/// conflict with the impls for types like `[T; N]`, which may be [`Finite`]
+/// without being [`Exhaustive`]. If you implement [`Exhaustive`] manually,
+/// implement this trait too, with [`Finite::COUNT`] as `Self::Num::USIZE` and
+/// [`Finite::rank`] as [`Exhaustive::index`].
+///
+/// [`Finite::COUNT`] is computed at compile time. If the number of values of a
+/// type does not fit in a [`u128`], using the type fails to compile.
+///
+/// # Safety
+///
+/// All possible values of this type, as representable in memory, must have a
+/// unique rank below [`Finite::COUNT`], and [`Finite::unrank`] must be the
+/// inverse of [`Finite::rank`].
+///
+/// # Examples
+///
+/// ```
+/// use const_exhaustive::{Exhaustive, Finite};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+/// enum Direction {
+///     North,
+///     East,
+///     South,
+///     West,
+/// }
+///
+/// // too many values to fit in `Exhaustive::ALL`
+/// type Grid = [[Option<Direction>; 6]; 6];
+///
+/// assert_eq!(5u128.pow(36), Grid::COUNT);
+///
+/// let grid = Grid::unrank(Grid::COUNT - 1).unwrap();
+/// assert_eq!([[Some(Direction::West); 6]; 6], grid);
+/// assert_eq!(Grid::COUNT - 1, grid.rank());
+/// assert_eq!(None, Grid::unrank(Grid::COUNT));
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "the number of values of `{Self}` is not known statically",
+    label = "not finite",
+    note = "consider annotating `{Self}` with `#[derive(Finite)]`"
+)]
+pub unsafe trait Finite: Sized + Copy {
+    /// Number of values that may exist of this type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use const_exhaustive::Finite;
+    ///
+    /// assert_eq!(2, bool::COUNT);
+    /// assert_eq!(1 << 64, <[bool; 64]>::COUNT);
+    /// ```
+    const COUNT: u128;
+
+    /// Gets the position of this value among all values of this type.
+    ///
+    /// This is named differently to [`Exhaustive::index`], so that both can be
+    /// called on a type which implements both traits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use const_exhaustive::Finite;
+    ///
+    /// assert_eq!(0, [false; 64].rank());
+    /// assert_eq!(5, [false, true, false, true].rank());
+    /// assert_eq!(u128::from(u64::MAX), [true; 64].rank());
+    /// ```
+    fn rank(self) -> u128;
+
+    /// Gets the value at position `rank` among all values of this type.
+    ///
+    /// Returns [`None`] if `rank` is out of bounds, i.e. greater than or equal
+    /// to [`Finite::COUNT`].
+    ///
+    /// This is the inverse of [`Finite::rank`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use const_exhaustive::Finite;
+    ///
+    /// assert_eq!(Some([false; 64]), <[bool; 64]>::unrank(0));
+    /// assert_eq!(Some([true; 64]), <[bool; 64]>::unrank(u128::from(u64::MAX)));
+    /// assert_eq!(None, <[bool; 64]>::unrank(1 << 64));
+    /// ```
+    #[must_use]
+    fn unrank(rank: u128) -> Option<Self>;
+}
+
+// types with few enough values that we can reuse their `Exhaustive` impl
+macro_rules! impl_from_exhaustive {
+    ($($(#[$meta:meta])* $ty:ty),* $(,)?) => {
+        $(
+            $(#[$meta])*
+            unsafe impl Finite for $ty {
+                const COUNT: u128 = count_of::<Self>();
+
+                fn rank(self) -> u128 {
+                    self.index() as u128
+                }
+
+                fn unrank(rank: u128) -> Option<Self> {
+                    usize::try_from(rank).ok().and_then(Self::from_index)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_exhaustive!(
+    Infallible,
+    (),
+    PhantomPinned,
+    bool,
+    Ordering,
+    Alignment,
+    FpCategory,
+    #[cfg(feature = "small-ints")]
+    u8,
+    #[cfg(feature = "small-ints")]
+    i8,
+    #[cfg(feature = "medium-ints")]
+    u16,
+    #[cfg(feature = "medium-ints")]
+    i16,
+    #[cfg(feature = "small-ints")]
+    core::num::NonZero<u8>,
+    #[cfg(feature = "small-ints")]
+    core::num::NonZero<i8>,
+);
+
+unsafe impl<T: ?Sized> Finite for PhantomData<T> {
+    const COUNT: u128 = 1;
+
+    fn rank(self) -> u128 {
+        0
+    }
+
+    fn unrank(rank: u128) -> Option<Self> {
+        (rank == 0).then_some(Self)
+    }
+}
+
+// wrappers which have exactly the same values as their inner type
+macro_rules! impl_wrapper {
+    ($ty:ident, | $t:ident | $wrap:expr, | $w:ident | $unwrap:expr $(,)?) => {
+        unsafe impl<T: Finite> Finite for $ty<T> {
+            const COUNT: u128 = T::COUNT;
+
+            fn rank(self) -> u128 {
+                let $w = self;
+                $unwrap.rank()
+            }
+
+            fn unrank(rank: u128) -> Option<Self> {
+                T::unrank(rank).map(|$t| $wrap)
+            }
+        }
+    };
+}
+
+impl_wrapper!(Reverse, |t| Reverse(t), |w| w.0);
+impl_wrapper!(Wrapping, |t| Wrapping(t), |w| w.0);
+impl_wrapper!(Saturating, |t| Saturating(t), |w| w.0);
+impl_wrapper!(ManuallyDrop, |t| ManuallyDrop::new(t), |w| {
+    ManuallyDrop::into_inner(w)
+},);
+
+unsafe impl<T: Finite> Finite for Option<T> {
+    const COUNT: u128 = 1 + T::COUNT;
+
+    fn rank(self) -> u128 {
+        self.map_or(0, |t| 1 + t.rank())
+    }
+
+    fn unrank(rank: u128) -> Option<Self> {
+        match rank {
+            0 => Some(None),
+            _ => T::unrank(rank - 1).map(Some),
+        }
+    }
+}
+
+unsafe impl<T: Finite, E: Finite> Finite for Result<T, E> {
+    const COUNT: u128 = T::COUNT + E::COUNT;
+
+    fn rank(self) -> u128 {
+        match self {
+            Ok(t) => t.rank(),
+            Err(e) => T::COUNT + e.rank(),
+        }
+    }
+
+    fn unrank(rank: u128) -> Option<Self> {
+        if rank < T::COUNT {
+            T::unrank(rank).map(Ok)
+        } else {
+            E::unrank(rank - T::COUNT).map(Err)
+        }
+    }
+}
+
+unsafe impl<T: Finite> Finite for Bound<T> {
+    const COUNT: u128 = T::COUNT * 2 + 1;
+
+    fn rank(self) -> u128 {
+        match self {
+            Self::Included(t) => t.rank(),
+            Self::Excluded(t) => T::COUNT + t.rank(),
+            Self::Unbounded => T::COUNT * 2,
+        }
+    }
+
+    fn unrank(rank: u128) -> Option<Self> {
+        if rank < T::COUNT {
+            T::unrank(rank).map(Self::Included)
+        } else if rank < T::COUNT * 2 {
+            T::unrank(rank - T::COUNT).map(Self::Excluded)
+        } else {
+            (rank == T::COUNT * 2).then_some(Self::Unbounded)
+        }
+    }
+}
+
+unsafe impl<B: Finite, C: Finite> Finite for ControlFlow<B, C> {
+    const COUNT: u128 = C::COUNT + B::COUNT;
+
+    fn rank(self) -> u128 {
+        match self {
+            Self::Continue(c) => c.rank(),
+            Self::Break(b) => C::COUNT + b.rank(),
+        }
+    }
+
+    fn unrank(rank: u128) -> Option<Self> {
+        if rank < C::COUNT {
+            C::unrank(rank).map(Self::Continue)
+        } else {
+            B::unrank(rank - C::COUNT).map(Self::Break)
+        }
+    }
+}
+
+unsafe impl<T: Finite> Finite for Poll<T> {
+    const COUNT: u128 = T::COUNT + 1;
+
+    fn rank(self) -> u128 {
+        match self {
+            Self::Ready(t) => t.rank(),
+            Self::Pending => T::COUNT,
+        }
+    }
+
+    fn unrank(rank: u128) -> Option<Self> {
+        if rank < T::COUNT {
+            T::unrank(rank).map(Self::Ready)
+        } else {
+            (rank == T::COUNT).then_some(Self::Pending)
+        }
+    }
+}
+
+unsafe impl<T: Finite, const N: usize> Finite for [T; N] {
+    const COUNT: u128 = count_product(&[T::COUNT; N]);
+
+    fn rank(self) -> u128 {
+        join_rank(self.map(T::rank), [T::COUNT; N])
+    }
+
+    fn unrank(rank: u128) -> Option<Self> {
+        if rank >= Self::COUNT {
+            return None;
+        }
+        let ranks = split_rank(rank, [T::COUNT; N]);
+        Some(ranks.map(|rank| T::unrank(rank).expect("rank should be in bounds")))
+    }
+}
+
+macro_rules! impl_variadic {
+    ($(#[$meta:meta])* $(($T:ident, $t:ident)),*) => {
+        $(#[$meta])*
+        unsafe impl<$($T: Finite,)*> Finite for ($($T,)*) {
+            const COUNT: u128 = count_product(&[$($T::COUNT,)*]);
+
+            fn rank(self) -> u128 {
+                let ($($t,)*) = self;
+                join_rank([$($t.rank(),)*], [$($T::COUNT,)*])
+            }
+
+            fn unrank(rank: u128) -> Option<Self> {
+                if rank >= Self::COUNT {
+                    return None;
+                }
+                let [$($t,)*] = split_rank(rank, [$($T::COUNT,)*]);
+                Some(($($T::unrank($t)?,)*))
+            }
+        }
+    };
+}
+
+all_tuples!(
+    #[doc(fake_variadic)]
+    impl_variadic,
+    1,
+    16,
+    T,
+    t
+);
+
+// number of values of an `Exhaustive` type, as a `Finite::COUNT`
+// must be `pub` since it is used in `exhaustive_newtype!`
+#[doc(hidden)]
+#[must_use]
+pub const fn count_of<T: Exhaustive>() -> u128 {
+    T::Num::USIZE as u128
+}
+
+// these are the same as `join_index` and `split_index`, but for `u128`
+
+// must be `pub` since it is used in `#[derive(Finite)]`
+#[doc(hidden)]
+#[must_use]
+pub const fn count_product(counts: &[u128]) -> u128 {
+    let mut product = 1;
+    let mut i = 0;
+    while i < counts.len() {
+        product *= counts[i];
+        i += 1;
+    }
+    product
+}
+
+// must be `pub` since it is used in `#[derive(Finite)]`
+#[doc(hidden)]
+#[must_use]
+pub const fn join_rank<const N: usize>(ranks: [u128; N], counts: [u128; N]) -> u128 {
+    let mut rank = 0;
+    let mut i = 0;
+    while i < N {
+        rank = rank * counts[i] + ranks[i];
+        i += 1;
+    }
+    rank
+}
+
+// must be `pub` since it is used in `#[derive(Finite)]`
+#[doc(hidden)]
+#[must_use]
+pub const fn split_rank<const N: usize>(mut rank: u128, counts: [u128; N]) -> [u128; N] {
+    let mut result = [0; N];
+    let mut i = 0;
+    while i < N {
+        result[N - 1 - i] = rank % counts[N - 1 - i];
+        rank /= counts[N - 1 - i];
+        i += 1;
+    }
+    result
+}
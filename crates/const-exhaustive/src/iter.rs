@@ -0,0 +1,77 @@
+use {
+    crate::Exhaustive,
+    core::{iter::FusedIterator, marker::PhantomData, ops::Range},
+    typenum::Unsigned,
+};
+
+/// Iterator over all values of an [`Exhaustive`] type.
+///
+/// Values are produced by calling [`Exhaustive::from_index`] on each index in
+/// `0..T::Num`, rather than by copying them out of [`Exhaustive::ALL`]. This
+/// means that [`Iterator::nth`] and friends run in constant time.
+///
+/// See [`Exhaustive::iter`].
+#[derive(Debug, Clone)]
+pub struct Iter<T> {
+    indices: Range<usize>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Exhaustive> Iter<T> {
+    /// Creates an iterator over all values of `T`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            indices: 0..T::Num::USIZE,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Exhaustive> Default for Iter<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn get<T: Exhaustive>(index: usize) -> T {
+    T::from_index(index).expect("index should be in bounds")
+}
+
+impl<T: Exhaustive> Iterator for Iter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.indices.next().map(get)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.indices.len()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.indices.nth(n).map(get)
+    }
+}
+
+impl<T: Exhaustive> DoubleEndedIterator for Iter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.indices.next_back().map(get)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.indices.nth_back(n).map(get)
+    }
+}
+
+impl<T: Exhaustive> ExactSizeIterator for Iter<T> {}
+
+impl<T: Exhaustive> FusedIterator for Iter<T> {}
@@ -6,25 +6,68 @@
 #![doc = include_str!("../README.md")]
 #![no_std]
 
-mod array;
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
+// must be `pub` since it is used in exported macros
+#[doc(hidden)]
+pub mod array;
+#[cfg(feature = "alloc")]
+mod covering;
+mod enumerable;
+mod finite;
+mod iter;
+mod map;
+mod newtype;
+mod order;
+mod ranged;
+mod samples;
+mod set;
+mod verify;
+mod via;
+
+#[cfg(feature = "alloc")]
+pub use covering::{pairwise, t_wise};
 use {
     array::{concat, from_fn, map},
     const_default::ConstDefault,
     core::{
+        cmp::{Ordering, Reverse},
         convert::Infallible,
+        fmt::Alignment,
         marker::{PhantomData, PhantomPinned},
-        mem::MaybeUninit,
-        ops::{Add, Mul},
+        mem::{ManuallyDrop, MaybeUninit},
+        num::{FpCategory, Saturating, Wrapping},
+        ops::{Add, Bound, ControlFlow, Mul},
+        task::Poll,
     },
     generic_array::{ArrayLength, GenericArray},
-    typenum::{Const, Pow, Sum, ToUInt, U, U0, U1, U2, Unsigned},
+    typenum::{ATerm, Const, Pow, Sum, TArr, ToUInt, U, U0, U1, U2, U3, U5, Unsigned},
     variadics_please::all_tuples,
 };
 pub use {
-    const_exhaustive_derive::Exhaustive,
+    const_exhaustive_derive::{
+        ConstEq, Enumerable, Exhaustive, Finite, Samples, const_eq, exhaustive_test,
+    },
+    enumerable::Enumerable,
+    finite::{Finite, count_of, count_product, join_rank, split_rank},
     generic_array::{self, const_transmute},
+    iter::Iter,
+    map::ExhaustiveMap,
+    newtype::NewtypeValue,
+    order::{
+        ColexOrder, ExhaustiveProduct, GrayOrder, ReverseOrder, colex_to_lex, gray_to_lex,
+        lex_to_colex, lex_to_gray,
+    },
+    ranged::{
+        RangedLenI8, RangedLenI16, RangedLenI32, RangedLenI64, RangedLenIsize, RangedLenU8,
+        RangedLenU16, RangedLenU32, RangedLenU64, RangedLenUsize,
+    },
+    samples::Samples,
+    set::{BitLength, ExhaustiveSet, SetIter, Subsets},
     typenum,
+    verify::debug_verify,
+    via::{ExhaustiveVia, Via},
 };
 
 /// All values of this type are known at compile time.
@@ -42,14 +85,37 @@ pub use {
 ///     such as strings
 ///
 /// This trait is implemented for most types in `core` for which it makes sense.
-/// However, it is not implemented for any numerical types. Although there are
-/// practically a finite set of numbers for any given type (because they have to
-/// fit in a finite number of bits, e.g. a [`u8`] must fit in 8 bits), there are
-/// theoretically an infinite number of numbers, which goes against the spirit
-/// of this trait.
+/// However, it is not implemented for any numerical types by default. Although
+/// there are practically a finite set of numbers for any given type (because
+/// they have to fit in a finite number of bits, e.g. a [`u8`] must fit in 8
+/// bits), there are theoretically an infinite number of numbers, which goes
+/// against the spirit of this trait.
+///
+/// If you do want to treat small integers as exhaustive, e.g. for lookup
+/// tables, you can enable these features:
+/// - `small-ints`: implements [`Exhaustive`] for [`u8`] and [`i8`] (256
+///   values), and their [`NonZero`](core::num::NonZero) counterparts (255
+///   values)
+/// - `medium-ints`: implements [`Exhaustive`] for [`u16`] and [`i16`] (65536
+///   values), and enables `small-ints`
+///
+/// Integer values are ordered in the same way as their [`Ord`] implementation.
+/// Be aware that [`Exhaustive::ALL`] is computed at compile time, so using it
+/// on a `medium-ints` type will noticeably slow down compilation, and
+/// composing them (e.g. `(u16, u16)`) will most likely fail to compile.
+/// Prefer [`Exhaustive::iter`], [`Exhaustive::index`] and
+/// [`Exhaustive::from_index`], which do not touch [`Exhaustive::ALL`]. For
+/// types with too many values to ever fit in [`Exhaustive::ALL`], use
+/// [`Finite`] instead.
+///
+/// If a type can't be [`Copy`], e.g. because it holds a `String`, it can
+/// implement [`Enumerable`] instead.
 ///
 /// However, you may still want to define an exhaustive integer, where values
 /// may only be in a specific range e.g. `0..4`. In this case, you can either:
+/// - use one of the ranged integer types, such as [`RangedLenU8`]
+/// - use [`exhaustive_newtype!`] to define a wrapper type which may only hold
+///   values in a range, or from a list
 /// - define an enum with each value explicitly
 /// - write a wrapper type which ensures that the value within it is always in
 ///   range, then `unsafe impl Exhaustive` on the wrapper
@@ -59,15 +125,21 @@ pub use {
 /// All possible values of this type, as representable in memory, must be
 /// present in [`Exhaustive::ALL`].
 ///
+/// To catch some mistakes in a manual impl, use [`verify_exhaustive!`] to check
+/// for duplicate values at compile time, and [`debug_verify`] to check the
+/// whole impl in a test.
+///
 /// Example of implementing [`Exhaustive`] manually:
 ///
 /// ```
-/// use const_exhaustive::{Exhaustive, generic_array::GenericArray, typenum};
+/// use const_exhaustive::{
+///     ConstEq, Exhaustive, debug_verify, generic_array::GenericArray, typenum, verify_exhaustive,
+/// };
 ///
 /// // if you were implementing this for real,
 /// // you should probably use `NonZero<u8>` or `nonmax::NonMaxU8` for niche optimization;
 /// // but this is a simplified example
-/// #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ConstEq)]
 /// struct UintUpTo4(u8);
 ///
 /// impl UintUpTo4 {
@@ -87,8 +159,15 @@ pub use {
 ///
 ///     const ALL: GenericArray<Self, Self::Num> =
 ///         GenericArray::from_array([Self(0), Self(1), Self(2), Self(3)]);
+///
+///     fn index(self) -> usize {
+///         usize::from(self.0)
+///     }
 /// }
 ///
+/// verify_exhaustive!(UintUpTo4);
+/// debug_verify::<UintUpTo4>();
+///
 /// assert_eq!(
 ///     [
 ///         None,
@@ -155,6 +234,10 @@ pub unsafe trait Exhaustive: Sized + Copy {
     /// on this type. Some examples of this ordering are outlined below. All
     /// first-party implementations of [`Exhaustive`] follow this ordering.
     ///
+    /// To enumerate values in a different order, wrap the type in
+    /// [`GrayOrder`], [`ColexOrder`] or [`ReverseOrder`], or use
+    /// `#[exhaustive(order = "...")]` when deriving.
+    ///
     /// ## Primitives
     ///
     /// - [`Infallible`] has no values
@@ -228,38 +311,284 @@ pub unsafe trait Exhaustive: Sized + Copy {
     /// );
     /// ```
     const ALL: GenericArray<Self, Self::Num>;
+
+    /// Gets the position of this value in [`Exhaustive::ALL`].
+    ///
+    /// This is computed arithmetically from the value itself, rather than by
+    /// searching through [`Exhaustive::ALL`], so it runs in constant time
+    /// relative to [`Exhaustive::Num`].
+    ///
+    /// This is not a `const fn`, since `const` trait methods are not yet
+    /// stable. In a const context, you can instead search
+    /// [`Exhaustive::ALL`] using the inherent `const_eq` method generated by
+    /// [`#[derive(ConstEq)]`](const_exhaustive_derive::ConstEq).
+    ///
+    /// Unlike [`Exhaustive::from_index`], this has no default implementation,
+    /// since a value can't be searched for in [`Exhaustive::ALL`] without
+    /// [`PartialEq`]. Manual implementations written before version 2.0.0 must
+    /// add it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use const_exhaustive::Exhaustive;
+    ///
+    /// assert_eq!(0, None::<bool>.index());
+    /// assert_eq!(2, Some(true).index());
+    /// assert_eq!(3, (true, true).index());
+    /// ```
+    fn index(self) -> usize;
+
+    /// Gets the value at position `index` in [`Exhaustive::ALL`].
+    ///
+    /// Returns [`None`] if `index` is out of bounds, i.e. greater than or equal
+    /// to [`Exhaustive::Num`].
+    ///
+    /// This is the inverse of [`Exhaustive::index`]. All first-party
+    /// implementations, and `#[derive(Exhaustive)]`, compute this value
+    /// arithmetically. The default implementation copies the value out of
+    /// [`Exhaustive::ALL`].
+    ///
+    /// Like [`Exhaustive::index`], this is not a `const fn`. In a const
+    /// context, index into [`Exhaustive::ALL`] directly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use const_exhaustive::Exhaustive;
+    ///
+    /// assert_eq!(Some(None), Option::<bool>::from_index(0));
+    /// assert_eq!(Some(Some(true)), Option::<bool>::from_index(2));
+    /// assert_eq!(None, Option::<bool>::from_index(3));
+    /// ```
+    #[must_use]
+    fn from_index(index: usize) -> Option<Self> {
+        Self::ALL.as_slice().get(index).copied()
+    }
+
+    /// Iterates over all values of this type, in the order of
+    /// [`Exhaustive::ALL`].
+    ///
+    /// Unlike iterating over [`Exhaustive::ALL`], this does not copy the whole
+    /// array of values - each value is created on demand using
+    /// [`Exhaustive::from_index`]. Skipping values is done in constant time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use const_exhaustive::Exhaustive;
+    ///
+    /// let mut iter = <[Option<bool>; 8]>::iter();
+    /// assert_eq!(6561, iter.len());
+    /// assert_eq!(Some([None; 8]), iter.next());
+    /// assert_eq!(Some([Some(true); 8]), iter.next_back());
+    /// assert_eq!(
+    ///     Some([None, None, None, None, None, None, Some(true), Some(false)]),
+    ///     iter.nth(6),
+    /// );
+    /// ```
+    #[must_use]
+    fn iter() -> Iter<Self> {
+        Iter::new()
+    }
 }
 
 unsafe impl Exhaustive for Infallible {
     type Num = U0;
 
     const ALL: GenericArray<Self, Self::Num> = GenericArray::from_array([]);
+
+    fn index(self) -> usize {
+        match self {}
+    }
+
+    fn from_index(_: usize) -> Option<Self> {
+        None
+    }
 }
 
 unsafe impl Exhaustive for () {
     type Num = U1;
 
     const ALL: GenericArray<Self, Self::Num> = GenericArray::from_array([()]);
+
+    fn index(self) -> usize {
+        0
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        (index == 0).then_some(())
+    }
+}
+
+unsafe impl ExhaustiveProduct for () {
+    const RADICES: &'static [usize] = &[];
 }
 
 unsafe impl Exhaustive for PhantomPinned {
     type Num = U1;
 
     const ALL: GenericArray<Self, Self::Num> = GenericArray::from_array([Self]);
+
+    fn index(self) -> usize {
+        0
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        (index == 0).then_some(Self)
+    }
 }
 
 unsafe impl<T: ?Sized> Exhaustive for PhantomData<T> {
     type Num = U1;
 
     const ALL: GenericArray<Self, Self::Num> = GenericArray::from_array([Self]);
+
+    fn index(self) -> usize {
+        0
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        (index == 0).then_some(Self)
+    }
 }
 
 unsafe impl Exhaustive for bool {
     type Num = U2;
 
     const ALL: GenericArray<Self, Self::Num> = GenericArray::from_array([false, true]);
+
+    fn index(self) -> usize {
+        usize::from(self)
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        match index {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+}
+
+// values are ordered from `MIN` to `MAX`, which we get by offsetting the
+// index from `MIN`; this works for both signed and unsigned types
+#[cfg(feature = "small-ints")]
+macro_rules! impl_int {
+    ($int:ty, $uint:ty, $num:ty) => {
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_possible_wrap,
+            clippy::cast_sign_loss,
+            clippy::unnecessary_cast,
+            reason = "we have no other way to cast in a const context"
+        )]
+        unsafe impl Exhaustive for $int {
+            type Num = $num;
+
+            const ALL: GenericArray<Self, Self::Num> =
+                from_fn!(Self::Num, |i| <$int>::MIN.wrapping_add(i as $int));
+
+            fn index(self) -> usize {
+                usize::from(self.wrapping_sub(<$int>::MIN) as $uint)
+            }
+
+            fn from_index(index: usize) -> Option<Self> {
+                <$uint>::try_from(index)
+                    .ok()
+                    .map(|index| <$int>::MIN.wrapping_add(index as $int))
+            }
+        }
+    };
 }
 
+// same as `impl_int`, but we skip over zero, which is at `$zero_index`
+#[cfg(feature = "small-ints")]
+macro_rules! impl_nonzero {
+    ($int:ty, $uint:ty, $num:ty, $zero_index:literal) => {
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_possible_wrap,
+            clippy::cast_sign_loss,
+            clippy::unnecessary_cast,
+            reason = "we have no other way to cast in a const context"
+        )]
+        #[allow(unused_comparisons, reason = "zero is at index 0 for unsigned types")]
+        unsafe impl Exhaustive for core::num::NonZero<$int> {
+            type Num = $num;
+
+            const ALL: GenericArray<Self, Self::Num> = from_fn!(Self::Num, |i| {
+                let i = if i < $zero_index { i } else { i + 1 };
+                Self::new(<$int>::MIN.wrapping_add(i as $int)).expect("should not be zero")
+            });
+
+            fn index(self) -> usize {
+                let index = usize::from(self.get().wrapping_sub(<$int>::MIN) as $uint);
+                if index < $zero_index {
+                    index
+                } else {
+                    index - 1
+                }
+            }
+
+            fn from_index(index: usize) -> Option<Self> {
+                if index >= Self::Num::USIZE {
+                    return None;
+                }
+                let index = if index < $zero_index {
+                    index
+                } else {
+                    index + 1
+                };
+                Self::new(<$int>::MIN.wrapping_add(index as $int))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "small-ints")]
+impl_int!(u8, u8, typenum::U256);
+#[cfg(feature = "small-ints")]
+impl_int!(i8, u8, typenum::U256);
+#[cfg(feature = "medium-ints")]
+impl_int!(u16, u16, typenum::U65536);
+#[cfg(feature = "medium-ints")]
+impl_int!(i16, u16, typenum::U65536);
+#[cfg(feature = "small-ints")]
+impl_nonzero!(u8, u8, typenum::U255, 0);
+#[cfg(feature = "small-ints")]
+impl_nonzero!(i8, u8, typenum::U255, 128);
+
+// wrappers which have exactly the same values as their inner type
+macro_rules! impl_wrapper {
+    ($ty:ident, | $t:ident | $wrap:expr, | $w:ident | $unwrap:expr $(,)?) => {
+        unsafe impl<T: Exhaustive> Exhaustive for $ty<T>
+        where
+            T::Num: ArrayLength<ArrayType<Self>: Copy>,
+        {
+            type Num = T::Num;
+
+            const ALL: GenericArray<Self, Self::Num> = map!(T::ALL, |$t| $wrap);
+
+            fn index(self) -> usize {
+                let $w = self;
+                $unwrap.index()
+            }
+
+            fn from_index(index: usize) -> Option<Self> {
+                T::from_index(index).map(|$t| $wrap)
+            }
+        }
+    };
+}
+
+impl_wrapper!(Reverse, |t| Reverse(t), |w| w.0);
+impl_wrapper!(Wrapping, |t| Wrapping(t), |w| w.0);
+impl_wrapper!(Saturating, |t| Saturating(t), |w| w.0);
+impl_wrapper!(ManuallyDrop, |t| ManuallyDrop::new(t), |w| {
+    ManuallyDrop::into_inner(w)
+},);
+
 unsafe impl<T: Exhaustive> Exhaustive for Option<T>
 where
     U1: Add<T::Num, Output: ArrayLength<ArrayType<Self>: Copy>>,
@@ -268,6 +597,17 @@ where
 
     const ALL: GenericArray<Self, Self::Num> =
         concat::<_, U1, T::Num>(GenericArray::from_array([None]), map!(T::ALL, |t| Some(t)));
+
+    fn index(self) -> usize {
+        self.map_or(0, |t| 1 + t.index())
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        match index {
+            0 => Some(None),
+            _ => T::from_index(index - 1).map(Some),
+        }
+    }
 }
 
 unsafe impl<T: Exhaustive, E: Exhaustive> Exhaustive for Result<T, E>
@@ -280,6 +620,189 @@ where
         map!(T::ALL, |t| Ok::<T, E>(t)),
         map!(E::ALL, |t| Err::<T, E>(t)),
     );
+
+    fn index(self) -> usize {
+        match self {
+            Ok(t) => t.index(),
+            Err(e) => T::Num::USIZE + e.index(),
+        }
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        if index < T::Num::USIZE {
+            T::from_index(index).map(Ok)
+        } else {
+            E::from_index(index - T::Num::USIZE).map(Err)
+        }
+    }
+}
+
+unsafe impl Exhaustive for Ordering {
+    type Num = U3;
+
+    const ALL: GenericArray<Self, Self::Num> =
+        GenericArray::from_array([Self::Less, Self::Equal, Self::Greater]);
+
+    fn index(self) -> usize {
+        match self {
+            Self::Less => 0,
+            Self::Equal => 1,
+            Self::Greater => 2,
+        }
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        match index {
+            0 => Some(Self::Less),
+            1 => Some(Self::Equal),
+            2 => Some(Self::Greater),
+            _ => None,
+        }
+    }
+}
+
+unsafe impl Exhaustive for Alignment {
+    type Num = U3;
+
+    const ALL: GenericArray<Self, Self::Num> =
+        GenericArray::from_array([Self::Left, Self::Right, Self::Center]);
+
+    fn index(self) -> usize {
+        match self {
+            Self::Left => 0,
+            Self::Right => 1,
+            Self::Center => 2,
+        }
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        match index {
+            0 => Some(Self::Left),
+            1 => Some(Self::Right),
+            2 => Some(Self::Center),
+            _ => None,
+        }
+    }
+}
+
+unsafe impl Exhaustive for FpCategory {
+    type Num = U5;
+
+    const ALL: GenericArray<Self, Self::Num> = GenericArray::from_array([
+        Self::Nan,
+        Self::Infinite,
+        Self::Zero,
+        Self::Subnormal,
+        Self::Normal,
+    ]);
+
+    fn index(self) -> usize {
+        match self {
+            Self::Nan => 0,
+            Self::Infinite => 1,
+            Self::Zero => 2,
+            Self::Subnormal => 3,
+            Self::Normal => 4,
+        }
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        match index {
+            0 => Some(Self::Nan),
+            1 => Some(Self::Infinite),
+            2 => Some(Self::Zero),
+            3 => Some(Self::Subnormal),
+            4 => Some(Self::Normal),
+            _ => None,
+        }
+    }
+}
+
+unsafe impl<T: Exhaustive> Exhaustive for Bound<T>
+where
+    T::Num: Add<T::Num, Output: ArrayLength + Add<U1, Output: ArrayLength<ArrayType<Self>: Copy>>>,
+{
+    type Num = Sum<Sum<T::Num, T::Num>, U1>;
+
+    const ALL: GenericArray<Self, Self::Num> = concat::<_, Sum<T::Num, T::Num>, U1>(
+        concat::<_, T::Num, T::Num>(
+            map!(T::ALL, |t| Self::Included(t)),
+            map!(T::ALL, |t| Self::Excluded(t)),
+        ),
+        GenericArray::from_array([Self::Unbounded]),
+    );
+
+    fn index(self) -> usize {
+        match self {
+            Self::Included(t) => t.index(),
+            Self::Excluded(t) => T::Num::USIZE + t.index(),
+            Self::Unbounded => T::Num::USIZE * 2,
+        }
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        if index < T::Num::USIZE {
+            T::from_index(index).map(Self::Included)
+        } else if index < T::Num::USIZE * 2 {
+            T::from_index(index - T::Num::USIZE).map(Self::Excluded)
+        } else {
+            (index == T::Num::USIZE * 2).then_some(Self::Unbounded)
+        }
+    }
+}
+
+unsafe impl<B: Exhaustive, C: Exhaustive> Exhaustive for ControlFlow<B, C>
+where
+    C::Num: Add<B::Num, Output: ArrayLength<ArrayType<Self>: Copy>>,
+{
+    type Num = Sum<C::Num, B::Num>;
+
+    const ALL: GenericArray<Self, Self::Num> = concat::<_, C::Num, B::Num>(
+        map!(C::ALL, |c| Self::Continue(c)),
+        map!(B::ALL, |b| Self::Break(b)),
+    );
+
+    fn index(self) -> usize {
+        match self {
+            Self::Continue(c) => c.index(),
+            Self::Break(b) => C::Num::USIZE + b.index(),
+        }
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        if index < C::Num::USIZE {
+            C::from_index(index).map(Self::Continue)
+        } else {
+            B::from_index(index - C::Num::USIZE).map(Self::Break)
+        }
+    }
+}
+
+unsafe impl<T: Exhaustive> Exhaustive for Poll<T>
+where
+    T::Num: Add<U1, Output: ArrayLength<ArrayType<Self>: Copy>>,
+{
+    type Num = Sum<T::Num, U1>;
+
+    const ALL: GenericArray<Self, Self::Num> = concat::<_, T::Num, U1>(
+        map!(T::ALL, |t| Self::Ready(t)),
+        GenericArray::from_array([Self::Pending]),
+    );
+
+    fn index(self) -> usize {
+        match self {
+            Self::Ready(t) => t.index(),
+            Self::Pending => T::Num::USIZE,
+        }
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        if index < T::Num::USIZE {
+            T::from_index(index).map(Self::Ready)
+        } else {
+            (index == T::Num::USIZE).then_some(Self::Pending)
+        }
+    }
 }
 
 unsafe impl<T: Exhaustive, const N: usize> Exhaustive for [T; N]
@@ -299,8 +822,29 @@ where
             let index = (i / T::Num::USIZE.pow(N as u32 - j as u32 - 1)) % T::Num::USIZE;
             T::ALL.as_slice()[index]
         });
-        perm
+        perm.into_array()
     });
+
+    fn index(self) -> usize {
+        join_index(self.map(T::index), [T::Num::USIZE; N])
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        if index >= Self::Num::USIZE {
+            return None;
+        }
+        let indices = split_index(index, [T::Num::USIZE; N]);
+        Some(indices.map(|index| T::from_index(index).expect("index should be in bounds")))
+    }
+}
+
+unsafe impl<T: Exhaustive, const N: usize> ExhaustiveProduct for [T; N]
+where
+    Const<N>: ToUInt<Output: ArrayLength>,
+    <T::Num as ArrayLength>::ArrayType<usize>: ConstDefault,
+    T::Num: Pow<U<N>, Output: ArrayLength<ArrayType<Self>: Copy>>,
+{
+    const RADICES: &'static [usize] = &[T::Num::USIZE; N];
 }
 
 // based on:
@@ -356,6 +900,28 @@ macro_rules! impl_variadic {
 
                 unsafe { const_transmute(all) }
             };
+
+            fn index(self) -> usize {
+                let ($($t,)*) = self;
+                join_index([$($t.index(),)*], [$($T::Num::USIZE,)*])
+            }
+
+            fn from_index(index: usize) -> Option<Self> {
+                if index >= Self::Num::USIZE {
+                    return None;
+                }
+                let [$($t,)*] = split_index(index, [$($T::Num::USIZE,)*]);
+                Some(($($T::from_index($t)?,)*))
+            }
+        }
+
+        $(#[$meta])*
+        unsafe impl<$($T: Exhaustive,)*> ExhaustiveProduct for ($($T,)*)
+        where
+            ($($T::Num,)*): MulAll,
+            <ProdAll<($($T::Num,)*)> as ArrayLength>::ArrayType<Self>: Copy,
+        {
+            const RADICES: &'static [usize] = &[$($T::Num::USIZE,)*];
         }
     };
 }
@@ -369,7 +935,112 @@ all_tuples!(
     t
 );
 
-const fn split_index<const N: usize>(mut index: usize, lengths: [usize; N]) -> [usize; N] {
+// `#[derive(Exhaustive)]` computes `Num` through these traits, rather than by
+// writing out `Add` and `Mul` bounds on each field's `Num` directly.
+//
+// The type lists passed in here contain the field types themselves, not their
+// `Num`s. If a where-clause contains e.g. `<bool as Exhaustive>::Num`, rustc
+// normalizes it to `U2` in some places but not others, and then fails to match
+// the where-clause against the obligation it is meant to prove. This breaks
+// deriving on types like `enum Event<T> { Tick, Input(T) }`.
+//
+// Each item in a field list is usually the field type, but fields with an
+// attribute like `#[exhaustive(values = ..)]` or `#[exhaustive(with = ..)]` use
+// a marker type instead, which is why the list is bounded on `FieldNum` rather
+// than `Exhaustive`.
+//
+// `#[derive(Samples)]` uses the same lists, but wraps each field type in
+// `FieldSamples`, since a type's number of samples may be different from its
+// number of values.
+
+// must be `pub` since it is used in `#[derive(Exhaustive)]`
+#[doc(hidden)]
+pub trait FieldNum {
+    type Num: ArrayLength;
+}
+
+impl<T: Exhaustive> FieldNum for T {
+    type Num = T::Num;
+}
+
+// must be `pub` since it is used in `#[derive(Samples)]`
+#[doc(hidden)]
+pub struct FieldSamples<T>(PhantomData<T>);
+
+impl<T: Samples> FieldNum for FieldSamples<T> {
+    type Num = T::Num;
+}
+
+// must be `pub` since it is used in `#[derive(Exhaustive)]`
+#[doc(hidden)]
+pub struct FieldLen<const N: usize>;
+
+impl<const N: usize> FieldNum for FieldLen<N>
+where
+    Const<N>: ToUInt<Output: ArrayLength>,
+{
+    type Num = U<N>;
+}
+
+// must be `pub` since it is used in `#[derive(Exhaustive)]`
+#[doc(hidden)]
+pub struct FieldVia<R, T>(PhantomData<(R, T)>);
+
+impl<T: Copy, R: ExhaustiveVia<T>> FieldNum for FieldVia<R, T> {
+    type Num = R::Num;
+}
+
+// must be `pub` since it is used in `#[derive(Exhaustive)]`
+#[doc(hidden)]
+pub trait FieldsNum {
+    type Output: ArrayLength;
+}
+
+impl FieldsNum for ATerm {
+    type Output = U1;
+}
+
+impl<T: FieldNum, Rest: FieldsNum> FieldsNum for TArr<T, Rest>
+where
+    T::Num: Mul<Rest::Output, Output: ArrayLength>,
+{
+    type Output = <T::Num as Mul<Rest::Output>>::Output;
+}
+
+// must be `pub` since it is used in `#[derive(Exhaustive)]`
+#[doc(hidden)]
+pub trait VariantsNum {
+    type Output: ArrayLength;
+}
+
+impl VariantsNum for ATerm {
+    type Output = U0;
+}
+
+impl<Fields: FieldsNum, Rest: VariantsNum> VariantsNum for TArr<Fields, Rest>
+where
+    Fields::Output: Add<Rest::Output, Output: ArrayLength>,
+{
+    type Output = <Fields::Output as Add<Rest::Output>>::Output;
+}
+
+// must be `pub` since it is used in `#[derive(Exhaustive)]`
+#[doc(hidden)]
+#[must_use]
+pub const fn join_index<const N: usize>(indices: [usize; N], lengths: [usize; N]) -> usize {
+    let mut index = 0;
+    let mut i = 0;
+    while i < N {
+        index = index * lengths[i] + indices[i];
+        i += 1;
+    }
+    index
+}
+
+// must be `pub` since it is used in `#[derive(Exhaustive)]`
+#[doc(hidden)]
+#[must_use]
+pub const fn split_index<const N: usize>(mut index: usize, lengths: [usize; N]) -> [usize; N] {
     let mut result = [0; N];
     let mut i = 0;
     while i < N {
@@ -0,0 +1,247 @@
+use {
+    crate::{Exhaustive, Iter},
+    core::{
+        fmt::{self, Debug},
+        hash::{Hash, Hasher},
+        iter::Zip,
+        ops::{Index, IndexMut},
+    },
+    generic_array::{GenericArray, GenericArrayIter, functional::FunctionalSequence},
+};
+
+/// Map which stores exactly one value for every key of an [`Exhaustive`] type.
+///
+/// This is backed by a [`GenericArray`] of values, where the value for a key
+/// is stored at the key's [`Exhaustive::index`]. Since every key always has a
+/// value, there is no `insert` or `remove`, and [`ExhaustiveMap::get`] never
+/// fails.
+///
+/// To build a map in a const context, use
+/// [`exhaustive_map!`](crate::exhaustive_map).
+///
+/// # Examples
+///
+/// ```
+/// use const_exhaustive::{Exhaustive, ExhaustiveMap, exhaustive_map};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+/// enum Direction {
+///     North,
+///     South,
+///     East,
+///     West,
+/// }
+///
+/// const OPPOSITE: ExhaustiveMap<Direction, Direction> = exhaustive_map!(Direction, |dir| {
+///     match dir {
+///         Direction::North => Direction::South,
+///         Direction::South => Direction::North,
+///         Direction::East => Direction::West,
+///         Direction::West => Direction::East,
+///     }
+/// });
+///
+/// assert_eq!(Direction::South, OPPOSITE[Direction::North]);
+///
+/// let mut visits = ExhaustiveMap::<Direction, u32>::default();
+/// visits[Direction::East] += 1;
+/// assert_eq!(
+///     [0, 0, 1, 0],
+///     visits.values().copied().collect::<Vec<_>>().as_slice()
+/// );
+/// ```
+pub struct ExhaustiveMap<K: Exhaustive, V> {
+    values: GenericArray<V, K::Num>,
+}
+
+impl<K: Exhaustive, V> ExhaustiveMap<K, V> {
+    /// Creates a map from an array of values, in the order of
+    /// [`Exhaustive::ALL`].
+    #[must_use]
+    pub const fn from_array(values: GenericArray<V, K::Num>) -> Self {
+        Self { values }
+    }
+
+    /// Creates a map by calling `f` on every key, in the order of
+    /// [`Exhaustive::ALL`].
+    ///
+    /// This cannot be used in a const context, since closures can't be called
+    /// in a `const fn` on stable Rust. To build a map in a const context, use
+    /// [`exhaustive_map!`](crate::exhaustive_map) instead, which takes the
+    /// same closure-like syntax.
+    #[must_use]
+    pub fn from_fn(f: impl FnMut(K) -> V) -> Self {
+        Self::from_array(K::iter().map(f).collect())
+    }
+
+    /// Gets a reference to the values of this map, in the order of
+    /// [`Exhaustive::ALL`].
+    #[must_use]
+    pub const fn as_array(&self) -> &GenericArray<V, K::Num> {
+        &self.values
+    }
+
+    /// Gets a mutable reference to the values of this map, in the order of
+    /// [`Exhaustive::ALL`].
+    #[must_use]
+    pub const fn as_mut_array(&mut self) -> &mut GenericArray<V, K::Num> {
+        &mut self.values
+    }
+
+    /// Takes the values out of this map, in the order of [`Exhaustive::ALL`].
+    #[must_use]
+    pub fn into_array(self) -> GenericArray<V, K::Num> {
+        self.values
+    }
+
+    /// Gets a reference to the value for `key`.
+    #[must_use]
+    pub fn get(&self, key: K) -> &V {
+        &self.values[key.index()]
+    }
+
+    /// Gets a mutable reference to the value for `key`.
+    #[must_use]
+    pub fn get_mut(&mut self, key: K) -> &mut V {
+        &mut self.values[key.index()]
+    }
+
+    /// Iterates over all keys, in the order of [`Exhaustive::ALL`].
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = K> + ExactSizeIterator + use<K, V> {
+        K::iter()
+    }
+
+    /// Iterates over references to all values, in the order of
+    /// [`Exhaustive::ALL`].
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &V> + ExactSizeIterator {
+        self.values.iter()
+    }
+
+    /// Iterates over mutable references to all values, in the order of
+    /// [`Exhaustive::ALL`].
+    pub fn values_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut V> + ExactSizeIterator {
+        self.values.iter_mut()
+    }
+
+    /// Iterates over all keys and references to their values, in the order of
+    /// [`Exhaustive::ALL`].
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (K, &V)> + ExactSizeIterator {
+        self.keys().zip(self.values())
+    }
+
+    /// Iterates over all keys and mutable references to their values, in the
+    /// order of [`Exhaustive::ALL`].
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = (K, &mut V)> + ExactSizeIterator {
+        self.keys().zip(self.values_mut())
+    }
+
+    /// Creates a map with the same keys, where each value is the result of
+    /// calling `f` on the key and the existing value.
+    #[must_use]
+    pub fn map<U>(self, mut f: impl FnMut(K, V) -> U) -> ExhaustiveMap<K, U> {
+        ExhaustiveMap::from_array(K::iter().zip(self.values).map(|(k, v)| f(k, v)).collect())
+    }
+
+    /// Combines this map with another map with the same keys, pairing up the
+    /// values for each key.
+    #[must_use]
+    pub fn zip<U>(self, other: ExhaustiveMap<K, U>) -> ExhaustiveMap<K, (V, U)> {
+        ExhaustiveMap::from_array(self.values.zip(other.values, |a, b| (a, b)))
+    }
+}
+
+/// Creates an [`ExhaustiveMap`] by evaluating an expression for every key.
+///
+/// Unlike [`ExhaustiveMap::from_fn`], this can be used in a const context,
+/// since the expression is expanded inline instead of being called as a
+/// closure.
+///
+/// # Examples
+///
+/// ```
+/// use const_exhaustive::{ExhaustiveMap, exhaustive_map};
+///
+/// const NAMES: ExhaustiveMap<Option<bool>, &str> = exhaustive_map!(Option<bool>, |value| {
+///     match value {
+///         None => "none",
+///         Some(false) => "false",
+///         Some(true) => "true",
+///     }
+/// });
+///
+/// assert_eq!("false", NAMES[Some(false)]);
+/// ```
+#[macro_export]
+macro_rules! exhaustive_map {
+    ($key:ty, | $k:pat_param | $f:expr $(,)?) => {
+        $crate::ExhaustiveMap::<$key, _>::from_array($crate::__map!(
+            <$key as $crate::Exhaustive>::ALL,
+            |$k| $f
+        ))
+    };
+}
+
+impl<K: Exhaustive, V> Index<K> for ExhaustiveMap<K, V> {
+    type Output = V;
+
+    fn index(&self, key: K) -> &Self::Output {
+        self.get(key)
+    }
+}
+
+impl<K: Exhaustive, V> IndexMut<K> for ExhaustiveMap<K, V> {
+    fn index_mut(&mut self, key: K) -> &mut Self::Output {
+        self.get_mut(key)
+    }
+}
+
+impl<K: Exhaustive, V> IntoIterator for ExhaustiveMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = Zip<Iter<K>, GenericArrayIter<V, K::Num>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        K::iter().zip(self.values)
+    }
+}
+
+#[expect(
+    clippy::expl_impl_clone_on_copy,
+    reason = "`Copy` has stricter bounds than `Clone`"
+)]
+impl<K: Exhaustive, V: Clone> Clone for ExhaustiveMap<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            values: self.values.clone(),
+        }
+    }
+}
+
+impl<K: Exhaustive, V: Copy> Copy for ExhaustiveMap<K, V> where GenericArray<V, K::Num>: Copy {}
+
+impl<K: Exhaustive, V: Default> Default for ExhaustiveMap<K, V> {
+    fn default() -> Self {
+        Self {
+            values: GenericArray::default(),
+        }
+    }
+}
+
+impl<K: Exhaustive + Debug, V: Debug> Debug for ExhaustiveMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Exhaustive, V: PartialEq> PartialEq for ExhaustiveMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.values == other.values
+    }
+}
+
+impl<K: Exhaustive, V: Eq> Eq for ExhaustiveMap<K, V> {}
+
+impl<K: Exhaustive, V: Hash> Hash for ExhaustiveMap<K, V> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.values.hash(state);
+    }
+}
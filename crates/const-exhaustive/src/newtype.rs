@@ -0,0 +1,410 @@
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+/// Value stored inside a type created by
+/// [`exhaustive_newtype!`](crate::exhaustive_newtype).
+///
+/// This can only be created unsafely, so that safe code in the same module as
+/// the newtype can't create a value which isn't in [`Exhaustive::ALL`].
+///
+/// `Tag` is the newtype itself, so that a value can't be moved from one
+/// newtype into another newtype over the same type, which may allow different
+/// values.
+///
+/// [`Exhaustive::ALL`]: crate::Exhaustive::ALL
+// must be `pub` since it is used in `exhaustive_newtype!`
+#[doc(hidden)]
+pub struct NewtypeValue<Tag, T> {
+    value: T,
+    _tag: PhantomData<Tag>,
+}
+
+impl<Tag, T: Copy> NewtypeValue<Tag, T> {
+    /// # Safety
+    ///
+    /// `value` must be one of the values allowed by the newtype `Tag`.
+    #[must_use]
+    pub const unsafe fn new_unchecked(value: T) -> Self {
+        Self {
+            value,
+            _tag: PhantomData,
+        }
+    }
+
+    #[must_use]
+    pub const fn get(self) -> T {
+        self.value
+    }
+}
+
+// these are implemented manually, since derives would add bounds on `Tag`
+
+impl<Tag, T: Clone> Clone for NewtypeValue<Tag, T> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            _tag: PhantomData,
+        }
+    }
+}
+
+impl<Tag, T: Copy> Copy for NewtypeValue<Tag, T> {}
+
+impl<Tag, T: PartialEq> PartialEq for NewtypeValue<Tag, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<Tag, T: Eq> Eq for NewtypeValue<Tag, T> {}
+
+impl<Tag, T: PartialOrd> PartialOrd for NewtypeValue<Tag, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<Tag, T: Ord> Ord for NewtypeValue<Tag, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<Tag, T: Hash> Hash for NewtypeValue<Tag, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl<Tag, T: fmt::Debug> fmt::Debug for NewtypeValue<Tag, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.value, f)
+    }
+}
+
+/// Creates a newtype over an integer or [`char`], which may only hold some
+/// specific values, and implements [`Exhaustive`] on it.
+///
+/// The allowed values can be given as:
+/// - a range of integers, e.g. `where 0..10` or `where -5..=5`
+/// - a range of [`char`]s, e.g. `where 'a'..='z'`
+/// - a list of values, e.g. `in [1, 2, 4, 8]`
+///
+/// Values of the type are ordered in [`Exhaustive::ALL`] in the same way as
+/// they are written, i.e. from the start to the end of a range, or in the
+/// order of the list. A list may not contain duplicate values.
+///
+/// The generated type has:
+/// - a private field, so that it can't hold a value which isn't allowed
+/// - `const fn new(value) -> Option<Self>`, which creates a value if it is
+///   allowed
+/// - `const fn get(self)`, which gets the underlying value
+/// - `const fn const_eq(&self, other: &Self) -> bool`, so it can be used with
+///   [`ConstEq`](crate::ConstEq) and
+///   [`verify_exhaustive!`](crate::verify_exhaustive)
+/// - derived [`Debug`], [`Clone`], [`Copy`], [`PartialEq`], [`Eq`],
+///   [`PartialOrd`], [`Ord`] and [`Hash`]
+/// - impls of [`Exhaustive`], [`Finite`](crate::Finite) and
+///   [`Samples`](crate::Samples)
+///
+/// [`Exhaustive::Num`] is computed from the number of allowed values, so the
+/// same limits apply as with [`typenum::U`] - if there are more than 1024
+/// values, the number must be a power of 2.
+///
+/// [`Exhaustive`]: crate::Exhaustive
+/// [`Exhaustive::ALL`]: crate::Exhaustive::ALL
+/// [`Exhaustive::Num`]: crate::Exhaustive::Num
+/// [`typenum::U`]: crate::typenum::U
+///
+/// # Examples
+///
+/// ```
+/// use const_exhaustive::{Exhaustive, exhaustive_newtype};
+///
+/// exhaustive_newtype! {
+///     /// Single decimal digit.
+///     pub struct Digit(u8) where 0..10;
+/// }
+///
+/// exhaustive_newtype! {
+///     pub struct Lowercase(char) where 'a'..='z';
+/// }
+///
+/// exhaustive_newtype! {
+///     pub struct Flag(u8) in [1, 2, 4, 8];
+/// }
+///
+/// assert_eq!(10, Digit::ALL.len());
+/// assert_eq!(Some(7), Digit::new(7).map(Digit::get));
+/// assert_eq!(None, Digit::new(10));
+///
+/// assert_eq!(26, Lowercase::ALL.len());
+/// assert_eq!(Some(2), Lowercase::new('c').map(Lowercase::index));
+///
+/// assert_eq!(
+///     [1, 2, 4, 8],
+///     Flag::iter().map(Flag::get).collect::<Vec<_>>().as_slice(),
+/// );
+/// assert_eq!(None, Flag::new(3));
+/// ```
+///
+/// A value can't be moved between two newtypes over the same type, even in the
+/// module which defines them:
+///
+/// ```compile_fail,E0308
+/// use const_exhaustive::exhaustive_newtype;
+///
+/// exhaustive_newtype! {
+///     pub struct Digit(u8) where 0..10;
+/// }
+///
+/// exhaustive_newtype! {
+///     pub struct Big(u8) where 0..=255;
+/// }
+///
+/// let digit = Digit(Big::new(200).unwrap().0);
+/// ```
+#[macro_export]
+macro_rules! exhaustive_newtype {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident(char) where $start:literal ..= $end:literal $(;)?
+    ) => {
+        $crate::__exhaustive_newtype! {
+            $(#[$meta])*
+            $vis struct $name(char),
+            len = {
+                assert!($start <= $end, "range must not be empty");
+                ($end as u32 - $start as u32 + 1) as usize
+            },
+            value = |i| match char::from_u32($start as u32 + i as u32) {
+                Some(value) => value,
+                None => panic!("range must not contain surrogate code points"),
+            },
+            index = |value| if $start <= value && value <= $end {
+                Some((value as u32 - $start as u32) as usize)
+            } else {
+                None
+            },
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident(char) where $start:literal .. $end:literal $(;)?
+    ) => {
+        $crate::__exhaustive_newtype! {
+            $(#[$meta])*
+            $vis struct $name(char),
+            len = {
+                assert!($start < $end, "range must not be empty");
+                ($end as u32 - $start as u32) as usize
+            },
+            value = |i| match char::from_u32($start as u32 + i as u32) {
+                Some(value) => value,
+                None => panic!("range must not contain surrogate code points"),
+            },
+            index = |value| if $start <= value && value < $end {
+                Some((value as u32 - $start as u32) as usize)
+            } else {
+                None
+            },
+        }
+    };
+    // bounds are cast to `$int` before being used, so that their literals have
+    // the type of the field, rather than defaulting to `i32`
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident($int:ty) where $start:literal ..= $end:literal $(;)?
+    ) => {
+        $crate::__exhaustive_newtype! {
+            $(#[$meta])*
+            $vis struct $name($int),
+            len = {
+                assert!(($start as $int) <= ($end as $int), "range must not be empty");
+                (($end as $int) as i128 - ($start as $int) as i128 + 1) as usize
+            },
+            value = |i| (($start as $int) as i128 + i as i128) as $int,
+            index = |value| if ($start as $int) <= value && value <= ($end as $int) {
+                Some((value as i128 - ($start as $int) as i128) as usize)
+            } else {
+                None
+            },
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident($int:ty) where $start:literal .. $end:literal $(;)?
+    ) => {
+        $crate::__exhaustive_newtype! {
+            $(#[$meta])*
+            $vis struct $name($int),
+            len = {
+                assert!(($start as $int) < ($end as $int), "range must not be empty");
+                (($end as $int) as i128 - ($start as $int) as i128) as usize
+            },
+            value = |i| (($start as $int) as i128 + i as i128) as $int,
+            index = |value| if ($start as $int) <= value && value < ($end as $int) {
+                Some((value as i128 - ($start as $int) as i128) as usize)
+            } else {
+                None
+            },
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident($int:ty) in [$($value:expr),+ $(,)?] $(;)?
+    ) => {
+        $crate::__exhaustive_newtype! {
+            $(#[$meta])*
+            $vis struct $name($int),
+            len = {
+                let values: &[$int] = &[$($value),+];
+                let mut i = 0;
+                while i < values.len() {
+                    let mut j = i + 1;
+                    while j < values.len() {
+                        assert!(values[i] != values[j], "values must not contain duplicates");
+                        j += 1;
+                    }
+                    i += 1;
+                }
+                values.len()
+            },
+            value = |i| {
+                let values: &[$int] = &[$($value),+];
+                values[i]
+            },
+            index = |value| {
+                let values: &[$int] = &[$($value),+];
+                let mut i = 0;
+                loop {
+                    if i >= values.len() {
+                        break None;
+                    }
+                    if values[i] == value {
+                        break Some(i);
+                    }
+                    i += 1;
+                }
+            },
+        }
+    };
+}
+
+// must be `pub` since it is used in `exhaustive_newtype!`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __exhaustive_newtype {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident($int:ty),
+        len = $len:expr,
+        value = |$i:ident| $value:expr,
+        index = |$v:ident| $index:expr $(,)?
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis struct $name($crate::NewtypeValue<$name, $int>);
+
+        #[allow(
+            clippy::cast_lossless,
+            clippy::cast_possible_truncation,
+            clippy::cast_possible_wrap,
+            clippy::cast_sign_loss,
+            clippy::unnecessary_cast,
+            reason = "we have no other way to cast in a const context"
+        )]
+        impl $name {
+            const LEN: usize = $len;
+
+            const fn value_at($i: usize) -> $int {
+                $value
+            }
+
+            const fn index_of($v: $int) -> ::core::option::Option<usize> {
+                $index
+            }
+
+            /// # Safety
+            ///
+            /// `index` must be less than `Self::LEN`.
+            const unsafe fn from_index_unchecked(index: usize) -> Self {
+                // SAFETY: `value_at` only returns allowed values for indices
+                // in bounds, which the caller guarantees
+                Self(unsafe { $crate::NewtypeValue::new_unchecked(Self::value_at(index)) })
+            }
+
+            /// Creates a value if `value` is one of the allowed values of
+            /// this type.
+            #[must_use]
+            $vis const fn new(value: $int) -> ::core::option::Option<Self> {
+                match Self::index_of(value) {
+                    ::core::option::Option::Some(index) => {
+                        // SAFETY: `index_of` only returns indices in bounds
+                        ::core::option::Option::Some(unsafe { Self::from_index_unchecked(index) })
+                    }
+                    ::core::option::Option::None => ::core::option::Option::None,
+                }
+            }
+
+            /// Gets the underlying value.
+            #[must_use]
+            $vis const fn get(self) -> $int {
+                self.0.get()
+            }
+
+            /// Checks if `self` and `other` are equal.
+            ///
+            /// This is the same as `==`, but can be used in a const context.
+            #[must_use]
+            $vis const fn const_eq(&self, other: &Self) -> bool {
+                self.0.get() == other.0.get()
+            }
+        }
+
+        // SAFETY: the field can only hold allowed values, and `ALL` contains
+        // each of them exactly once
+        unsafe impl $crate::Exhaustive for $name {
+            type Num = $crate::typenum::U<{ $name::LEN }>;
+
+            const ALL: $crate::generic_array::GenericArray<Self, Self::Num> =
+                // SAFETY: `i` is less than `Num`, which is `LEN`
+                $crate::__from_fn!(Self::Num, |i| unsafe { Self::from_index_unchecked(i) });
+
+            fn index(self) -> usize {
+                Self::index_of(self.get()).expect("value should be allowed")
+            }
+
+            fn from_index(index: usize) -> ::core::option::Option<Self> {
+                // SAFETY: we just checked that `index` is in bounds
+                (index < Self::LEN).then(|| unsafe { Self::from_index_unchecked(index) })
+            }
+        }
+
+        // SAFETY: same as above
+        unsafe impl $crate::Finite for $name {
+            const COUNT: u128 = $crate::count_of::<Self>();
+
+            fn rank(self) -> u128 {
+                $crate::Exhaustive::index(self) as u128
+            }
+
+            fn unrank(rank: u128) -> ::core::option::Option<Self> {
+                usize::try_from(rank)
+                    .ok()
+                    .and_then(<Self as $crate::Exhaustive>::from_index)
+            }
+        }
+
+        impl $crate::Samples for $name {
+            type Num = <Self as $crate::Exhaustive>::Num;
+
+            const SAMPLES: $crate::generic_array::GenericArray<Self, Self::Num> =
+                <Self as $crate::Exhaustive>::ALL;
+        }
+    };
+}
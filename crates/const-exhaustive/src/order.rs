@@ -0,0 +1,340 @@
+use {
+    crate::{Exhaustive, Finite, Samples, array::from_fn, count_of},
+    generic_array::{ArrayLength, GenericArray},
+    typenum::Unsigned,
+};
+
+/// [`Exhaustive`] type whose values are made up of a fixed number of
+/// independent fields, such as a tuple or an array.
+///
+/// This is used by [`GrayOrder`] and [`ColexOrder`] to reorder the values of a
+/// type field-by-field.
+///
+/// # Safety
+///
+/// [`ExhaustiveProduct::RADICES`] must multiply together to
+/// [`Exhaustive::Num`].
+pub unsafe trait ExhaustiveProduct: Exhaustive {
+    /// Number of values of each field of this type.
+    ///
+    /// [`Exhaustive::ALL`] must count through the values of these fields in
+    /// the "binary counting" order, where the first field is the most
+    /// significant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use const_exhaustive::ExhaustiveProduct;
+    ///
+    /// assert_eq!([2, 3], <(bool, Option<bool>)>::RADICES);
+    /// assert_eq!([2, 2, 2], <[bool; 3]>::RADICES);
+    /// ```
+    const RADICES: &'static [usize];
+}
+
+/// Wrapper around a `T`, whose values are ordered in reverse compared to `T`.
+///
+/// # Examples
+///
+/// ```
+/// use const_exhaustive::{Exhaustive, ReverseOrder};
+///
+/// assert_eq!(
+///     [
+///         ReverseOrder(Some(true)),
+///         ReverseOrder(Some(false)),
+///         ReverseOrder(None),
+///     ],
+///     ReverseOrder::<Option<bool>>::ALL.as_slice(),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct ReverseOrder<T>(pub T);
+
+unsafe impl<T: Exhaustive> Exhaustive for ReverseOrder<T>
+where
+    T::Num: ArrayLength<ArrayType<Self>: Copy>,
+{
+    type Num = T::Num;
+
+    const ALL: GenericArray<Self, Self::Num> = from_fn!(Self::Num, |i| Self(
+        T::ALL.as_slice()[T::Num::USIZE - 1 - i]
+    ));
+
+    fn index(self) -> usize {
+        T::Num::USIZE - 1 - self.0.index()
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        if index >= T::Num::USIZE {
+            return None;
+        }
+        T::from_index(T::Num::USIZE - 1 - index).map(Self)
+    }
+}
+
+impl<T: Exhaustive> Samples for ReverseOrder<T>
+where
+    T::Num: ArrayLength<ArrayType<Self>: Copy>,
+{
+    type Num = <Self as Exhaustive>::Num;
+
+    const SAMPLES: GenericArray<Self, Self::Num> = Self::ALL;
+}
+
+unsafe impl<T: Finite> Finite for ReverseOrder<T> {
+    const COUNT: u128 = T::COUNT;
+
+    fn rank(self) -> u128 {
+        T::COUNT - 1 - self.0.rank()
+    }
+
+    fn unrank(rank: u128) -> Option<Self> {
+        if rank >= T::COUNT {
+            return None;
+        }
+        T::unrank(T::COUNT - 1 - rank).map(Self)
+    }
+}
+
+/// Wrapper around a `T`, whose values are ordered in reflected Gray code
+/// order, where each value differs from the previous one in exactly one field.
+///
+/// Each field still counts through the values of its own type in order, but
+/// instead of wrapping around to its first value, it counts back down again.
+///
+/// # Examples
+///
+/// ```
+/// use const_exhaustive::{Exhaustive, GrayOrder};
+///
+/// assert_eq!(
+///     [
+///         GrayOrder((false, false)),
+///         GrayOrder((false, true)),
+///         GrayOrder((true, true)),
+///         GrayOrder((true, false)),
+///     ],
+///     GrayOrder::<(bool, bool)>::ALL.as_slice(),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct GrayOrder<T>(pub T);
+
+unsafe impl<T: ExhaustiveProduct> Exhaustive for GrayOrder<T>
+where
+    T::Num: ArrayLength<ArrayType<Self>: Copy>,
+{
+    type Num = T::Num;
+
+    const ALL: GenericArray<Self, Self::Num> = from_fn!(Self::Num, |i| Self(
+        T::ALL.as_slice()[gray_to_lex(i, T::RADICES)]
+    ));
+
+    fn index(self) -> usize {
+        lex_to_gray(self.0.index(), T::RADICES)
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        if index >= T::Num::USIZE {
+            return None;
+        }
+        T::from_index(gray_to_lex(index, T::RADICES)).map(Self)
+    }
+}
+
+impl<T: ExhaustiveProduct> Samples for GrayOrder<T>
+where
+    T::Num: ArrayLength<ArrayType<Self>: Copy>,
+{
+    type Num = <Self as Exhaustive>::Num;
+
+    const SAMPLES: GenericArray<Self, Self::Num> = Self::ALL;
+}
+
+unsafe impl<T: ExhaustiveProduct> Finite for GrayOrder<T>
+where
+    T::Num: ArrayLength<ArrayType<Self>: Copy>,
+{
+    const COUNT: u128 = count_of::<Self>();
+
+    fn rank(self) -> u128 {
+        self.index() as u128
+    }
+
+    fn unrank(rank: u128) -> Option<Self> {
+        usize::try_from(rank).ok().and_then(Self::from_index)
+    }
+}
+
+/// Wrapper around a `T`, whose values are ordered in colexicographic order,
+/// where the last field is the most significant, rather than the first.
+///
+/// # Examples
+///
+/// ```
+/// use const_exhaustive::{ColexOrder, Exhaustive};
+///
+/// assert_eq!(
+///     [
+///         ColexOrder((false, false)),
+///         ColexOrder((true, false)),
+///         ColexOrder((false, true)),
+///         ColexOrder((true, true)),
+///     ],
+///     ColexOrder::<(bool, bool)>::ALL.as_slice(),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct ColexOrder<T>(pub T);
+
+unsafe impl<T: ExhaustiveProduct> Exhaustive for ColexOrder<T>
+where
+    T::Num: ArrayLength<ArrayType<Self>: Copy>,
+{
+    type Num = T::Num;
+
+    const ALL: GenericArray<Self, Self::Num> = from_fn!(Self::Num, |i| Self(
+        T::ALL.as_slice()[colex_to_lex(i, T::RADICES)]
+    ));
+
+    fn index(self) -> usize {
+        lex_to_colex(self.0.index(), T::RADICES)
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        if index >= T::Num::USIZE {
+            return None;
+        }
+        T::from_index(colex_to_lex(index, T::RADICES)).map(Self)
+    }
+}
+
+impl<T: ExhaustiveProduct> Samples for ColexOrder<T>
+where
+    T::Num: ArrayLength<ArrayType<Self>: Copy>,
+{
+    type Num = <Self as Exhaustive>::Num;
+
+    const SAMPLES: GenericArray<Self, Self::Num> = Self::ALL;
+}
+
+unsafe impl<T: ExhaustiveProduct> Finite for ColexOrder<T>
+where
+    T::Num: ArrayLength<ArrayType<Self>: Copy>,
+{
+    const COUNT: u128 = count_of::<Self>();
+
+    fn rank(self) -> u128 {
+        self.index() as u128
+    }
+
+    fn unrank(rank: u128) -> Option<Self> {
+        usize::try_from(rank).ok().and_then(Self::from_index)
+    }
+}
+
+// In the functions below:
+// - a "lex" index is a position in "binary counting" order, where the first
+//   field is the most significant, as computed by `join_index`
+// - `lengths` is the number of values of each field
+// - `index` must be less than the product of `lengths`
+
+const fn product(lengths: &[usize]) -> usize {
+    let mut product = 1;
+    let mut i = 0;
+    while i < lengths.len() {
+        product *= lengths[i];
+        i += 1;
+    }
+    product
+}
+
+// must be `pub` since it is used in `#[derive(Exhaustive)]`
+#[doc(hidden)]
+#[must_use]
+pub const fn lex_to_gray(index: usize, lengths: &[usize]) -> usize {
+    // the direction of a field is reversed whenever the sum of the fields
+    // before it is odd
+    let mut rest = product(lengths);
+    let mut index = index;
+    let mut reversed = false;
+    let mut gray = 0;
+    let mut i = 0;
+    while i < lengths.len() {
+        rest /= lengths[i];
+        let digit = index / rest;
+        index %= rest;
+        let position = if reversed {
+            lengths[i] - 1 - digit
+        } else {
+            digit
+        };
+        gray = gray * lengths[i] + position;
+        reversed ^= digit % 2 == 1;
+        i += 1;
+    }
+    gray
+}
+
+// must be `pub` since it is used in `#[derive(Exhaustive)]`
+#[doc(hidden)]
+#[must_use]
+pub const fn gray_to_lex(index: usize, lengths: &[usize]) -> usize {
+    let mut rest = product(lengths);
+    let mut index = index;
+    let mut reversed = false;
+    let mut lex = 0;
+    let mut i = 0;
+    while i < lengths.len() {
+        rest /= lengths[i];
+        let position = index / rest;
+        index %= rest;
+        let digit = if reversed {
+            lengths[i] - 1 - position
+        } else {
+            position
+        };
+        lex = lex * lengths[i] + digit;
+        reversed ^= digit % 2 == 1;
+        i += 1;
+    }
+    lex
+}
+
+// must be `pub` since it is used in `#[derive(Exhaustive)]`
+#[doc(hidden)]
+#[must_use]
+pub const fn lex_to_colex(index: usize, lengths: &[usize]) -> usize {
+    // read digits from the least significant field in lex order, which is the
+    // most significant field in colex order
+    let mut index = index;
+    let mut colex = 0;
+    let mut i = lengths.len();
+    while i > 0 {
+        i -= 1;
+        let digit = index % lengths[i];
+        index /= lengths[i];
+        colex = colex * lengths[i] + digit;
+    }
+    colex
+}
+
+// must be `pub` since it is used in `#[derive(Exhaustive)]`
+#[doc(hidden)]
+#[must_use]
+pub const fn colex_to_lex(index: usize, lengths: &[usize]) -> usize {
+    let mut index = index;
+    let mut lex = 0;
+    let mut i = 0;
+    while i < lengths.len() {
+        let digit = index % lengths[i];
+        index /= lengths[i];
+        lex = lex * lengths[i] + digit;
+        i += 1;
+    }
+    lex
+}
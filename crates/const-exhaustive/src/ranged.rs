@@ -0,0 +1,317 @@
+use {
+    crate::{Exhaustive, Finite, Samples, array::from_fn},
+    core::fmt,
+    generic_array::{ArrayLength, GenericArray},
+    typenum::{Const, ToUInt, U},
+};
+
+// All arithmetic is done on the offset of a value from `MIN`, as an `i128`.
+// This is large enough to fit any offset, and any right-hand side of an
+// operation, for all integer types up to 64 bits.
+
+macro_rules! impl_ranged {
+    (#[doc = $summary:literal] $(#[$meta:meta])* $name:ident, $int:ty) => {
+        #[doc = $summary]
+        ///
+        /// The value is always in `MIN..MIN + LEN`, and there are exactly `LEN`
+        /// values of this type, so it implements [`Exhaustive`] with
+        /// [`Exhaustive::Num`] as `typenum::U<LEN>`.
+        ///
+        /// Note that the second parameter is the number of values, not the
+        /// largest value - e.g. `<10, 5>` holds `10..=14`. The largest value
+        /// is available as [`Self::MAX`]. The number of values can't be
+        /// computed from an inclusive `MIN, MAX` pair, since the type system
+        /// can't do arithmetic on const generics on stable Rust.
+        ///
+        /// `LEN` must be at least 1, and `MIN + LEN - 1` must fit in the
+        /// integer type - otherwise, creating a value of this type will fail
+        /// to compile.
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name<const MIN: $int, const LEN: usize>($int);
+
+        #[allow(
+            clippy::cast_lossless,
+            clippy::cast_possible_truncation,
+            clippy::cast_possible_wrap,
+            clippy::cast_sign_loss,
+            reason = "we have no other way to cast in a const context"
+        )]
+        impl<const MIN: $int, const LEN: usize> $name<MIN, LEN> {
+            /// Smallest value of this type.
+            pub const MIN: $int = MIN;
+
+            /// Largest value of this type.
+            pub const MAX: $int = {
+                assert!(LEN > 0, "range must not be empty");
+                assert!(
+                    MIN as i128 + (LEN as i128 - 1) <= <$int>::MAX as i128,
+                    "range must fit in the integer type"
+                );
+                (MIN as i128 + (LEN as i128 - 1)) as $int
+            };
+
+            /// Creates a value if `n` is in range.
+            #[must_use]
+            pub const fn new(n: $int) -> Option<Self> {
+                let offset = n as i128 - Self::MIN as i128;
+                if n <= Self::MAX && offset >= 0 {
+                    Some(Self(n))
+                } else {
+                    None
+                }
+            }
+
+            /// Creates a value, clamping `n` into range.
+            #[must_use]
+            pub const fn new_saturating(n: $int) -> Self {
+                Self::from_offset_saturating(n as i128 - Self::MIN as i128)
+            }
+
+            /// Creates a value, wrapping `n` around into range.
+            #[must_use]
+            pub const fn new_wrapping(n: $int) -> Self {
+                Self::from_offset_wrapping(n as i128 - Self::MIN as i128)
+            }
+
+            /// Gets the value as a primitive integer.
+            #[must_use]
+            pub const fn get(self) -> $int {
+                self.0
+            }
+
+            /// Checks if `self` and `other` are equal.
+            ///
+            /// This is the same as `==`, but can be used in a const context.
+            #[must_use]
+            pub const fn const_eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+
+            /// Adds `rhs`, returning [`None`] if the result is out of range.
+            #[must_use]
+            pub const fn checked_add(self, rhs: $int) -> Option<Self> {
+                Self::from_offset_checked(self.offset() + rhs as i128)
+            }
+
+            /// Subtracts `rhs`, returning [`None`] if the result is out of
+            /// range.
+            #[must_use]
+            pub const fn checked_sub(self, rhs: $int) -> Option<Self> {
+                Self::from_offset_checked(self.offset() - rhs as i128)
+            }
+
+            /// Adds `rhs`, clamping the result into range.
+            #[must_use]
+            pub const fn saturating_add(self, rhs: $int) -> Self {
+                Self::from_offset_saturating(self.offset() + rhs as i128)
+            }
+
+            /// Subtracts `rhs`, clamping the result into range.
+            #[must_use]
+            pub const fn saturating_sub(self, rhs: $int) -> Self {
+                Self::from_offset_saturating(self.offset() - rhs as i128)
+            }
+
+            /// Adds `rhs`, wrapping the result around into range.
+            #[must_use]
+            pub const fn wrapping_add(self, rhs: $int) -> Self {
+                Self::from_offset_wrapping(self.offset() + rhs as i128)
+            }
+
+            /// Subtracts `rhs`, wrapping the result around into range.
+            #[must_use]
+            pub const fn wrapping_sub(self, rhs: $int) -> Self {
+                Self::from_offset_wrapping(self.offset() - rhs as i128)
+            }
+
+            const fn offset(self) -> i128 {
+                self.0 as i128 - Self::MIN as i128
+            }
+
+            const fn from_offset(offset: i128) -> Self {
+                // make sure that the range is valid
+                let _ = Self::MAX;
+                Self((Self::MIN as i128 + offset) as $int)
+            }
+
+            const fn from_offset_checked(offset: i128) -> Option<Self> {
+                if offset >= 0 && offset < LEN as i128 {
+                    Some(Self::from_offset(offset))
+                } else {
+                    None
+                }
+            }
+
+            const fn from_offset_saturating(offset: i128) -> Self {
+                if offset < 0 {
+                    Self::from_offset(0)
+                } else if offset >= LEN as i128 {
+                    Self::from_offset(LEN as i128 - 1)
+                } else {
+                    Self::from_offset(offset)
+                }
+            }
+
+            const fn from_offset_wrapping(offset: i128) -> Self {
+                Self::from_offset(offset.rem_euclid(LEN as i128))
+            }
+        }
+
+        impl<const MIN: $int, const LEN: usize> From<$name<MIN, LEN>> for $int {
+            fn from(value: $name<MIN, LEN>) -> Self {
+                value.get()
+            }
+        }
+
+        impl<const MIN: $int, const LEN: usize> fmt::Display for $name<MIN, LEN> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_possible_wrap,
+            clippy::cast_sign_loss,
+            reason = "we have no other way to cast in a const context"
+        )]
+        unsafe impl<const MIN: $int, const LEN: usize> Exhaustive for $name<MIN, LEN>
+        where
+            Const<LEN>: ToUInt<Output: ArrayLength<ArrayType<Self>: Copy>>,
+        {
+            type Num = U<LEN>;
+
+            const ALL: GenericArray<Self, Self::Num> =
+                from_fn!(Self::Num, |i| Self::from_offset(i as i128));
+
+            fn index(self) -> usize {
+                self.offset() as usize
+            }
+
+            fn from_index(index: usize) -> Option<Self> {
+                (index < LEN).then(|| Self::from_offset(index as i128))
+            }
+        }
+
+        impl<const MIN: $int, const LEN: usize> Samples for $name<MIN, LEN>
+        where
+            Const<LEN>: ToUInt<Output: ArrayLength<ArrayType<Self>: Copy>>,
+        {
+            type Num = U<LEN>;
+
+            const SAMPLES: GenericArray<Self, Self::Num> = Self::ALL;
+        }
+
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_possible_wrap,
+            clippy::cast_sign_loss,
+            reason = "we have no other way to cast in a const context"
+        )]
+        unsafe impl<const MIN: $int, const LEN: usize> Finite for $name<MIN, LEN> {
+            // goes through `MAX` to make sure that the range is valid
+            const COUNT: u128 = (Self::MAX as i128 - MIN as i128 + 1) as u128;
+
+            fn rank(self) -> u128 {
+                self.offset() as u128
+            }
+
+            fn unrank(rank: u128) -> Option<Self> {
+                (rank < Self::COUNT).then(|| Self::from_offset(rank as i128))
+            }
+        }
+    };
+}
+
+impl_ranged!(
+    /// [`u8`] which is restricted to a range of values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use const_exhaustive::{Exhaustive, RangedLenU8};
+    ///
+    /// // 5 values, starting at 10 - so `10..=14`
+    /// type Level = RangedLenU8<10, 5>;
+    ///
+    /// assert_eq!(10, Level::MIN);
+    /// assert_eq!(14, Level::MAX);
+    /// assert_eq!(None, Level::new(9));
+    /// assert_eq!(None, Level::new(15));
+    ///
+    /// let level = Level::new(12).unwrap();
+    /// assert_eq!(12, level.get());
+    /// assert_eq!(None, level.checked_add(3));
+    /// assert_eq!(14, level.saturating_add(3).get());
+    /// assert_eq!(10, level.wrapping_add(3).get());
+    ///
+    /// assert_eq!(
+    ///     [10, 11, 12, 13, 14],
+    ///     Level::iter().map(Level::get).collect::<Vec<_>>().as_slice(),
+    /// );
+    /// ```
+    RangedLenU8,
+    u8
+);
+impl_ranged!(
+    /// [`u16`] which is restricted to a range of values.
+    RangedLenU16,
+    u16
+);
+impl_ranged!(
+    /// [`u32`] which is restricted to a range of values.
+    RangedLenU32,
+    u32
+);
+impl_ranged!(
+    /// [`u64`] which is restricted to a range of values.
+    RangedLenU64,
+    u64
+);
+impl_ranged!(
+    /// [`usize`] which is restricted to a range of values.
+    RangedLenUsize,
+    usize
+);
+impl_ranged!(
+    /// [`i8`] which is restricted to a range of values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use const_exhaustive::{Exhaustive, RangedLenI8};
+    ///
+    /// // 5 values, starting at -2 - so `-2..=2`
+    /// type Offset = RangedLenI8<-2, 5>;
+    ///
+    /// assert_eq!(-2, Offset::MIN);
+    /// assert_eq!(2, Offset::MAX);
+    /// assert_eq!(
+    ///     [-2, -1, 0, 1, 2],
+    ///     Offset::iter().map(Offset::get).collect::<Vec<_>>().as_slice(),
+    /// );
+    /// ```
+    RangedLenI8,
+    i8
+);
+impl_ranged!(
+    /// [`i16`] which is restricted to a range of values.
+    RangedLenI16,
+    i16
+);
+impl_ranged!(
+    /// [`i32`] which is restricted to a range of values.
+    RangedLenI32,
+    i32
+);
+impl_ranged!(
+    /// [`i64`] which is restricted to a range of values.
+    RangedLenI64,
+    i64
+);
+impl_ranged!(
+    /// [`isize`] which is restricted to a range of values.
+    RangedLenIsize,
+    isize
+);
@@ -0,0 +1,379 @@
+use {
+    crate::{
+        Exhaustive, MulAll, ProdAll,
+        array::{concat, from_fn, map},
+        split_index,
+    },
+    const_default::ConstDefault,
+    core::{
+        cmp::{Ordering, Reverse},
+        convert::Infallible,
+        fmt::Alignment,
+        marker::{PhantomData, PhantomPinned},
+        mem::{ManuallyDrop, MaybeUninit},
+        num::{FpCategory, NonZero, Saturating, Wrapping},
+        ops::{Add, Bound, ControlFlow},
+        task::Poll,
+    },
+    generic_array::{ArrayLength, GenericArray, const_transmute},
+    typenum::{Const, Pow, Sum, ToUInt, U, U1, U2, U3, U4, U5, U10, Unsigned},
+    variadics_please::all_tuples,
+};
+
+/// Type which has a set of representative values, known at compile time.
+///
+/// This is a weaker form of [`Exhaustive`], for types which have too many
+/// values to enumerate, such as [`u32`] or [`f32`]. Instead of all values,
+/// [`Samples::SAMPLES`] holds a chosen set of values which are likely to be
+/// interesting in a test, such as `0`, `1`, `MAX`, `NaN` or `-0.0`.
+///
+/// This trait should be derived instead of implemented manually - see
+/// [`const_exhaustive_derive::Samples`]. Deriving it on a type generates the
+/// same values as `#[derive(Exhaustive)]`, except that the values of each field
+/// are taken from [`Samples::SAMPLES`] instead of [`Exhaustive::ALL`].
+///
+/// This trait is implemented for every type in this crate which implements
+/// [`Exhaustive`], and `#[derive(Exhaustive)]` implements it too. For these
+/// types, [`Samples::SAMPLES`] is [`Exhaustive::ALL`], except for the integer
+/// types which the `small-ints` and `medium-ints` features make [`Exhaustive`]:
+/// `u8`, `i8`, `u16`, `i16`, `NonZero<u8>` and `NonZero<i8>`. These have the
+/// same samples as other integers whether or not the features are enabled, so
+/// that enabling a feature doesn't change the values which a test runs on.
+///
+/// This trait isn't implemented for all [`Exhaustive`] types, since that would
+/// conflict with the impls for these integers, and for types like `Option<T>`,
+/// which may be [`Samples`] without being [`Exhaustive`]. If you implement
+/// [`Exhaustive`] manually, implement this trait too, with
+/// [`Samples::SAMPLES`] as [`Exhaustive::ALL`].
+///
+/// Wrappers like [`Option`], [`Result`], tuples and arrays are [`Samples`] if
+/// their contents are, so e.g. `Option<u32>` has the samples of [`u32`] and
+/// [`None`]. This trait is also implemented for:
+/// - unsigned integers: `0`, `1` and `MAX`
+/// - signed integers: `MIN`, `-1`, `0`, `1` and `MAX`
+/// - [`NonZero`] integers: the same as the integer, without `0`
+/// - [`f32`] and [`f64`]: negative infinity, `MIN`, `-1.0`, `-0.0`, `0.0`,
+///   `MIN_POSITIVE`, `1.0`, `MAX`, infinity and `NaN`
+/// - [`char`]: `'\0'`, `'a'`, `'é'` and [`char::MAX`]
+/// - `&'static str`: `""`, `"a"`, `"hello world"` and `"héllo wörld"`
+///
+/// # Examples
+///
+/// ```
+/// use const_exhaustive::{Exhaustive, Samples};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+/// enum Unit {
+///     Meters,
+///     Feet,
+/// }
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Samples)]
+/// struct Length {
+///     value: u32,
+///     unit: Unit,
+/// }
+///
+/// assert_eq!(
+///     [
+///         Length {
+///             value: 0,
+///             unit: Unit::Meters
+///         },
+///         Length {
+///             value: 0,
+///             unit: Unit::Feet
+///         },
+///         Length {
+///             value: 1,
+///             unit: Unit::Meters
+///         },
+///         Length {
+///             value: 1,
+///             unit: Unit::Feet
+///         },
+///         Length {
+///             value: u32::MAX,
+///             unit: Unit::Meters
+///         },
+///         Length {
+///             value: u32::MAX,
+///             unit: Unit::Feet
+///         },
+///     ],
+///     Length::SAMPLES.as_slice(),
+/// );
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` has no representative values",
+    label = "no samples",
+    note = "consider annotating `{Self}` with `#[derive(Samples)]`"
+)]
+pub trait Samples: Sized + Copy {
+    /// Number of samples of this type.
+    ///
+    /// See [`Exhaustive::Num`].
+    type Num: ArrayLength<ArrayType<Self>: Copy>;
+
+    /// Representative values of this type.
+    ///
+    /// Values should be ordered in the same way as [`Exhaustive::ALL`], if it
+    /// makes sense on this type, and must not contain duplicates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use const_exhaustive::Samples;
+    ///
+    /// assert_eq!([false, true], bool::SAMPLES.as_slice());
+    /// assert_eq!([0, 1, u32::MAX], u32::SAMPLES.as_slice());
+    /// assert_eq!([i32::MIN, -1, 0, 1, i32::MAX], i32::SAMPLES.as_slice());
+    /// ```
+    const SAMPLES: GenericArray<Self, Self::Num>;
+}
+
+macro_rules! impl_samples {
+    ($($ty:ty, $num:ty, [$($value:expr),* $(,)?];)*) => {
+        $(
+            impl Samples for $ty {
+                type Num = $num;
+
+                const SAMPLES: GenericArray<Self, Self::Num> =
+                    GenericArray::from_array([$($value),*]);
+            }
+        )*
+    };
+}
+
+macro_rules! impl_unsigned {
+    ($($ty:ty),*) => {
+        impl_samples! {
+            $(
+                $ty, U3, [0, 1, <$ty>::MAX];
+                NonZero<$ty>, U2, [NonZero::<$ty>::MIN, NonZero::<$ty>::MAX];
+            )*
+        }
+    };
+}
+
+macro_rules! impl_signed {
+    ($($ty:ty),*) => {
+        impl_samples! {
+            $(
+                $ty, U5, [<$ty>::MIN, -1, 0, 1, <$ty>::MAX];
+                NonZero<$ty>, U4, [
+                    NonZero::<$ty>::MIN,
+                    NonZero::<$ty>::new(-1).expect("should not be zero"),
+                    NonZero::<$ty>::new(1).expect("should not be zero"),
+                    NonZero::<$ty>::MAX,
+                ];
+            )*
+        }
+    };
+}
+
+macro_rules! impl_float {
+    ($($ty:ty),*) => {
+        impl_samples! {
+            $(
+                $ty,
+                U10,
+                [
+                    <$ty>::NEG_INFINITY,
+                    <$ty>::MIN,
+                    -1.0,
+                    -0.0,
+                    0.0,
+                    <$ty>::MIN_POSITIVE,
+                    1.0,
+                    <$ty>::MAX,
+                    <$ty>::INFINITY,
+                    <$ty>::NAN,
+                ];
+            )*
+        }
+    };
+}
+
+// not taken from `Exhaustive`, even if `small-ints` or `medium-ints` are
+// enabled, so that enabling a feature doesn't change the samples
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_signed!(i8, i16, i32, i64, i128, isize);
+impl_float!(f32, f64);
+
+impl_samples! {
+    char, U4, ['\0', 'a', 'é', char::MAX];
+    &'static str, U4, ["", "a", "hello world", "héllo wörld"];
+}
+
+// types with few enough values that we can reuse their `Exhaustive` impl
+macro_rules! impl_from_exhaustive {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Samples for $ty {
+                type Num = <Self as Exhaustive>::Num;
+
+                const SAMPLES: GenericArray<Self, Self::Num> = Self::ALL;
+            }
+        )*
+    };
+}
+
+impl_from_exhaustive!(
+    Infallible,
+    (),
+    PhantomPinned,
+    bool,
+    Ordering,
+    Alignment,
+    FpCategory,
+);
+
+impl<T: ?Sized> Samples for PhantomData<T> {
+    type Num = U1;
+
+    const SAMPLES: GenericArray<Self, Self::Num> = GenericArray::from_array([Self]);
+}
+
+// wrappers which have exactly the same samples as their inner type
+macro_rules! impl_wrapper {
+    ($ty:ident, | $t:ident | $wrap:expr $(,)?) => {
+        impl<T: Samples> Samples for $ty<T>
+        where
+            T::Num: ArrayLength<ArrayType<Self>: Copy>,
+        {
+            type Num = T::Num;
+
+            const SAMPLES: GenericArray<Self, Self::Num> = map!(T::SAMPLES, |$t| $wrap);
+        }
+    };
+}
+
+impl_wrapper!(Reverse, |t| Reverse(t));
+impl_wrapper!(Wrapping, |t| Wrapping(t));
+impl_wrapper!(Saturating, |t| Saturating(t));
+impl_wrapper!(ManuallyDrop, |t| ManuallyDrop::new(t));
+
+// the impls below are the same as the `Exhaustive` impls, but built from
+// `SAMPLES` instead of `ALL`
+
+impl<T: Samples> Samples for Option<T>
+where
+    U1: Add<T::Num, Output: ArrayLength<ArrayType<Self>: Copy>>,
+{
+    type Num = Sum<U1, T::Num>;
+
+    const SAMPLES: GenericArray<Self, Self::Num> = concat::<_, U1, T::Num>(
+        GenericArray::from_array([None]),
+        map!(T::SAMPLES, |t| Some(t)),
+    );
+}
+
+impl<T: Samples, E: Samples> Samples for Result<T, E>
+where
+    T::Num: Add<E::Num, Output: ArrayLength<ArrayType<Self>: Copy>>,
+{
+    type Num = Sum<T::Num, E::Num>;
+
+    const SAMPLES: GenericArray<Self, Self::Num> = concat::<_, T::Num, E::Num>(
+        map!(T::SAMPLES, |t| Ok::<T, E>(t)),
+        map!(E::SAMPLES, |t| Err::<T, E>(t)),
+    );
+}
+
+impl<T: Samples> Samples for Bound<T>
+where
+    T::Num: Add<T::Num, Output: ArrayLength + Add<U1, Output: ArrayLength<ArrayType<Self>: Copy>>>,
+{
+    type Num = Sum<Sum<T::Num, T::Num>, U1>;
+
+    const SAMPLES: GenericArray<Self, Self::Num> = concat::<_, Sum<T::Num, T::Num>, U1>(
+        concat::<_, T::Num, T::Num>(
+            map!(T::SAMPLES, |t| Self::Included(t)),
+            map!(T::SAMPLES, |t| Self::Excluded(t)),
+        ),
+        GenericArray::from_array([Self::Unbounded]),
+    );
+}
+
+impl<B: Samples, C: Samples> Samples for ControlFlow<B, C>
+where
+    C::Num: Add<B::Num, Output: ArrayLength<ArrayType<Self>: Copy>>,
+{
+    type Num = Sum<C::Num, B::Num>;
+
+    const SAMPLES: GenericArray<Self, Self::Num> = concat::<_, C::Num, B::Num>(
+        map!(C::SAMPLES, |c| Self::Continue(c)),
+        map!(B::SAMPLES, |b| Self::Break(b)),
+    );
+}
+
+impl<T: Samples> Samples for Poll<T>
+where
+    T::Num: Add<U1, Output: ArrayLength<ArrayType<Self>: Copy>>,
+{
+    type Num = Sum<T::Num, U1>;
+
+    const SAMPLES: GenericArray<Self, Self::Num> = concat::<_, T::Num, U1>(
+        map!(T::SAMPLES, |t| Self::Ready(t)),
+        GenericArray::from_array([Self::Pending]),
+    );
+}
+
+impl<T: Samples, const N: usize> Samples for [T; N]
+where
+    Const<N>: ToUInt<Output: ArrayLength>,
+    <T::Num as ArrayLength>::ArrayType<usize>: ConstDefault,
+    T::Num: Pow<U<N>, Output: ArrayLength<ArrayType<Self>: Copy>>,
+{
+    type Num = <T::Num as Pow<U<N>>>::Output;
+
+    const SAMPLES: GenericArray<Self, Self::Num> = from_fn!(Self::Num, |i| {
+        let perm: GenericArray<T, U<N>> = from_fn!(U<N>, |j| {
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "we have no other way to cast in a const context"
+            )]
+            let index = (i / T::Num::USIZE.pow(N as u32 - j as u32 - 1)) % T::Num::USIZE;
+            T::SAMPLES.as_slice()[index]
+        });
+        perm.into_array()
+    });
+}
+
+macro_rules! impl_variadic {
+    ($(#[$meta:meta])* $(($T:ident, $t:ident)),*) => {
+        $(#[$meta])*
+        impl<$($T: Samples,)*> Samples for ($($T,)*)
+        where
+            ($($T::Num,)*): MulAll,
+            <ProdAll<($($T::Num,)*)> as ArrayLength>::ArrayType<Self>: Copy,
+        {
+            type Num = ProdAll<($($T::Num,)*)>;
+
+            const SAMPLES: GenericArray<Self, Self::Num> = {
+                let mut all: GenericArray<MaybeUninit<Self>, Self::Num> =
+                    unsafe { MaybeUninit::uninit().assume_init() };
+
+                let mut i = 0;
+                while i < <ProdAll<($($T::Num,)*)>>::USIZE {
+                    let [$($t,)*] = split_index(i, [$($T::Num::USIZE,)*]);
+                    let tuple = ($($T::SAMPLES.as_slice()[$t],)*);
+                    all.as_mut_slice()[i] = MaybeUninit::new(tuple);
+                    i += 1;
+                }
+
+                unsafe { const_transmute(all) }
+            };
+        }
+    };
+}
+
+all_tuples!(
+    #[doc(fake_variadic)]
+    impl_variadic,
+    1,
+    16,
+    T,
+    t
+);
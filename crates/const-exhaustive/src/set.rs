@@ -0,0 +1,578 @@
+use {
+    crate::{Exhaustive, Finite, Samples, array::from_fn},
+    core::{
+        fmt::{self, Debug},
+        hash::{Hash, Hasher},
+        iter::FusedIterator,
+        marker::PhantomData,
+        ops::{Add, BitAnd, BitOr, Div, Not, Range, Sub},
+    },
+    generic_array::{ArrayLength, GenericArray},
+    typenum::{Pow, Quot, Sum, U2, U7, U8, Unsigned},
+};
+
+// must be `pub` since it is used in `ExhaustiveSet`
+#[doc(hidden)]
+pub trait BitLength: ArrayLength {
+    type Bytes: ArrayLength<ArrayType<u8>: Copy>;
+}
+
+impl<N> BitLength for N
+where
+    N: ArrayLength + Add<U7, Output: Div<U8, Output: ArrayLength<ArrayType<u8>: Copy>>>,
+{
+    type Bytes = Quot<Sum<N, U7>, U8>;
+}
+
+// `const fn`s can't call function pointers, so we use a macro instead
+macro_rules! zip_bits {
+    ($a:expr, $b:expr, | $x:ident, $y:ident | $f:expr) => {{
+        let mut result = $a;
+        let bits = result.bits.as_mut_slice();
+        let other = $b.bits.as_slice();
+        let mut i = 0;
+        while i < bits.len() {
+            let ($x, $y) = (bits[i], other[i]);
+            bits[i] = $f;
+            i += 1;
+        }
+        result
+    }};
+}
+
+/// Set of values of an [`Exhaustive`] type, stored as a fixed-size bitset.
+///
+/// Every value of `T` is assigned one bit, at the value's
+/// [`Exhaustive::index`], so this set takes up `T::Num` bits (rounded up to
+/// the nearest byte), and never allocates.
+///
+/// This works for any [`Exhaustive`] type, not just fieldless enums.
+///
+/// Operations which take a value of `T` are not `const fn`, since they have to
+/// call [`Exhaustive::index`]. Each of them has a `const fn` counterpart which
+/// takes the value's index instead, such as [`ExhaustiveSet::insert_index`].
+/// Operations on whole sets, such as [`ExhaustiveSet::union`], are `const fn`.
+///
+/// # Examples
+///
+/// ```
+/// use const_exhaustive::{Exhaustive, ExhaustiveSet};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+/// enum Direction {
+///     North,
+///     South,
+///     East,
+///     West,
+/// }
+///
+/// let mut set = ExhaustiveSet::new();
+/// set.insert(Some((true, Direction::East)));
+/// set.insert(None);
+///
+/// assert_eq!(2, set.len());
+/// assert!(set.contains(None));
+/// assert!(!set.contains(Some((false, Direction::East))));
+/// assert_eq!(
+///     [None, Some((true, Direction::East))],
+///     set.iter().collect::<Vec<_>>().as_slice()
+/// );
+/// assert_eq!(7, set.complement().len());
+///
+/// // in a const context
+/// const NORTH_SOUTH: ExhaustiveSet<Direction> = {
+///     let mut set = ExhaustiveSet::new();
+///     set.insert_index(0);
+///     set.insert_index(1);
+///     set
+/// };
+/// assert!(NORTH_SOUTH.contains(Direction::South));
+/// assert!(!NORTH_SOUTH.contains(Direction::East));
+/// ```
+#[derive(Clone, Copy)]
+pub struct ExhaustiveSet<T: Exhaustive>
+where
+    T::Num: BitLength,
+{
+    bits: GenericArray<u8, <T::Num as BitLength>::Bytes>,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Exhaustive> ExhaustiveSet<T>
+where
+    T::Num: BitLength,
+{
+    /// Creates a set with no values.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            bits: from_fn!(<T::Num as BitLength>::Bytes, |_| 0u8),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a set with all values of `T`.
+    #[must_use]
+    pub const fn full() -> Self {
+        Self::new().complement()
+    }
+
+    /// Gets the number of values in this set.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        let bits = self.bits.as_slice();
+        let mut len = 0;
+        let mut i = 0;
+        while i < bits.len() {
+            len += bits[i].count_ones() as usize;
+            i += 1;
+        }
+        len
+    }
+
+    /// Checks if this set has no values.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Checks if `value` is in this set.
+    ///
+    /// Use [`ExhaustiveSet::contains_index`] in a const context.
+    #[must_use]
+    pub fn contains(&self, value: T) -> bool {
+        self.contains_index(value.index())
+    }
+
+    /// Adds `value` to this set.
+    ///
+    /// Returns whether the value was newly inserted.
+    ///
+    /// Use [`ExhaustiveSet::insert_index`] in a const context.
+    pub fn insert(&mut self, value: T) -> bool {
+        self.insert_index(value.index())
+    }
+
+    /// Removes `value` from this set.
+    ///
+    /// Returns whether the value was in the set.
+    ///
+    /// Use [`ExhaustiveSet::remove_index`] in a const context.
+    pub fn remove(&mut self, value: T) -> bool {
+        self.remove_index(value.index())
+    }
+
+    /// Checks if the value at `index` in [`Exhaustive::ALL`] is in this set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `T::Num`.
+    #[must_use]
+    pub const fn contains_index(&self, index: usize) -> bool {
+        let (byte, mask) = locate::<T>(index);
+        self.bits.as_slice()[byte] & mask != 0
+    }
+
+    /// Adds the value at `index` in [`Exhaustive::ALL`] to this set.
+    ///
+    /// Returns whether the value was newly inserted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `T::Num`.
+    pub const fn insert_index(&mut self, index: usize) -> bool {
+        let (byte, mask) = locate::<T>(index);
+        let bits = &mut self.bits.as_mut_slice()[byte];
+        let inserted = *bits & mask == 0;
+        *bits |= mask;
+        inserted
+    }
+
+    /// Removes the value at `index` in [`Exhaustive::ALL`] from this set.
+    ///
+    /// Returns whether the value was in the set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `T::Num`.
+    pub const fn remove_index(&mut self, index: usize) -> bool {
+        let (byte, mask) = locate::<T>(index);
+        let bits = &mut self.bits.as_mut_slice()[byte];
+        let removed = *bits & mask != 0;
+        *bits &= !mask;
+        removed
+    }
+
+    /// Removes all values from this set.
+    pub const fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Creates a set with all values which are in either `self` or `other`.
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        zip_bits!(self, other, |a, b| a | b)
+    }
+
+    /// Creates a set with all values which are in both `self` and `other`.
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        zip_bits!(self, other, |a, b| a & b)
+    }
+
+    /// Creates a set with all values which are in `self`, but not in
+    /// `other`.
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        zip_bits!(self, other, |a, b| a & !b)
+    }
+
+    /// Creates a set with all values which are in either `self` or `other`,
+    /// but not both.
+    #[must_use]
+    pub const fn symmetric_difference(self, other: Self) -> Self {
+        zip_bits!(self, other, |a, b| a ^ b)
+    }
+
+    /// Creates a set with all values of `T` which are not in `self`.
+    #[must_use]
+    pub const fn complement(self) -> Self {
+        let mut result = self;
+        let bits = result.bits.as_mut_slice();
+        let mut i = 0;
+        while i < bits.len() {
+            bits[i] = !bits[i];
+            i += 1;
+        }
+
+        // make sure that bits past the last value are never set
+        let rem = T::Num::USIZE % 8;
+        if rem != 0 {
+            bits[bits.len() - 1] &= (1 << rem) - 1;
+        }
+        result
+    }
+
+    /// Checks if all values in `self` are also in `other`.
+    #[must_use]
+    pub const fn is_subset(&self, other: &Self) -> bool {
+        self.difference(*other).is_empty()
+    }
+
+    /// Checks if all values in `other` are also in `self`.
+    #[must_use]
+    pub const fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Checks if `self` and `other` have no values in common.
+    #[must_use]
+    pub const fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(*other).is_empty()
+    }
+
+    /// Checks if `self` and `other` contain the same values.
+    ///
+    /// This is the same as `==`, but can be used in a const context.
+    #[must_use]
+    pub const fn const_eq(&self, other: &Self) -> bool {
+        self.symmetric_difference(*other).is_empty()
+    }
+
+    /// Iterates over all values in this set, in the order of
+    /// [`Exhaustive::ALL`].
+    #[must_use]
+    pub const fn iter(&self) -> SetIter<T> {
+        SetIter {
+            set: *self,
+            indices: 0..T::Num::USIZE,
+        }
+    }
+
+    // `[bool; N]` ordering: the value at index 0 is the most significant bit
+    const fn from_subset_index(index: usize) -> Self {
+        let mut set = Self::new();
+        let mut i = 0;
+        while i < T::Num::USIZE {
+            if (index >> (T::Num::USIZE - 1 - i)) & 1 == 1 {
+                set.insert_index(i);
+            }
+            i += 1;
+        }
+        set
+    }
+}
+
+/// Set of values of an [`Exhaustive`] type, as an element of the power set of
+/// that type.
+///
+/// This is the same type as [`ExhaustiveSet`], named to emphasize that it is
+/// [`Exhaustive`] itself - it has `2^T::Num` values, one for every subset of
+/// the values of `T`. Subsets are ordered in the same way as `[bool; N]`,
+/// where each `bool` represents whether a value of `T` is in the subset.
+///
+/// # Examples
+///
+/// ```
+/// use const_exhaustive::{Exhaustive, Subsets};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+/// enum Permission {
+///     Read,
+///     Write,
+/// }
+///
+/// let all = Subsets::<Permission>::ALL
+///     .iter()
+///     .map(|set| set.iter().collect::<Vec<_>>())
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     [
+///         vec![],
+///         vec![Permission::Write],
+///         vec![Permission::Read],
+///         vec![Permission::Read, Permission::Write],
+///     ],
+///     all.as_slice()
+/// );
+/// ```
+pub type Subsets<T> = ExhaustiveSet<T>;
+
+unsafe impl<T: Exhaustive> Exhaustive for ExhaustiveSet<T>
+where
+    T::Num: BitLength,
+    U2: Pow<T::Num, Output: ArrayLength<ArrayType<Self>: Copy>>,
+{
+    type Num = <U2 as Pow<T::Num>>::Output;
+
+    const ALL: GenericArray<Self, Self::Num> = from_fn!(Self::Num, |i| Self::from_subset_index(i));
+
+    fn index(self) -> usize {
+        (0..T::Num::USIZE)
+            .filter(|&i| self.contains_index(i))
+            .fold(0, |index, i| index | (1 << (T::Num::USIZE - 1 - i)))
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        (index < Self::Num::USIZE).then(|| Self::from_subset_index(index))
+    }
+}
+
+impl<T: Exhaustive> Samples for ExhaustiveSet<T>
+where
+    T::Num: BitLength,
+    U2: Pow<T::Num, Output: ArrayLength<ArrayType<Self>: Copy>>,
+{
+    type Num = <Self as Exhaustive>::Num;
+
+    const SAMPLES: GenericArray<Self, Self::Num> = Self::ALL;
+}
+
+// unlike `Exhaustive`, this works for sets of up to 127 values
+unsafe impl<T: Exhaustive> Finite for ExhaustiveSet<T>
+where
+    T::Num: BitLength,
+{
+    const COUNT: u128 = match 1u128.checked_shl(T::Num::U32) {
+        Some(count) => count,
+        None => panic!("a set of 128 or more values has too many subsets to count"),
+    };
+
+    fn rank(self) -> u128 {
+        (0..T::Num::USIZE)
+            .filter(|&i| self.contains_index(i))
+            .fold(0, |rank, i| rank | (1 << (T::Num::USIZE - 1 - i)))
+    }
+
+    fn unrank(rank: u128) -> Option<Self> {
+        if rank >= Self::COUNT {
+            return None;
+        }
+        let mut set = Self::new();
+        for i in 0..T::Num::USIZE {
+            if (rank >> (T::Num::USIZE - 1 - i)) & 1 == 1 {
+                set.insert_index(i);
+            }
+        }
+        Some(set)
+    }
+}
+
+const fn locate<T: Exhaustive>(index: usize) -> (usize, u8) {
+    assert!(index < T::Num::USIZE, "index out of bounds");
+    (index / 8, 1 << (index % 8))
+}
+
+/// Iterator over the values in an [`ExhaustiveSet`].
+///
+/// See [`ExhaustiveSet::iter`].
+#[derive(Clone)]
+pub struct SetIter<T: Exhaustive>
+where
+    T::Num: BitLength,
+{
+    set: ExhaustiveSet<T>,
+    indices: Range<usize>,
+}
+
+impl<T: Exhaustive> Iterator for SetIter<T>
+where
+    T::Num: BitLength,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self
+            .indices
+            .by_ref()
+            .find(|&index| self.set.contains_index(index))?;
+        T::from_index(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.indices.len()))
+    }
+}
+
+impl<T: Exhaustive> DoubleEndedIterator for SetIter<T>
+where
+    T::Num: BitLength,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self
+            .indices
+            .by_ref()
+            .rfind(|&index| self.set.contains_index(index))?;
+        T::from_index(index)
+    }
+}
+
+impl<T: Exhaustive> FusedIterator for SetIter<T> where T::Num: BitLength {}
+
+impl<T: Exhaustive> IntoIterator for ExhaustiveSet<T>
+where
+    T::Num: BitLength,
+{
+    type Item = T;
+    type IntoIter = SetIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Exhaustive> IntoIterator for &ExhaustiveSet<T>
+where
+    T::Num: BitLength,
+{
+    type Item = T;
+    type IntoIter = SetIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Exhaustive> FromIterator<T> for ExhaustiveSet<T>
+where
+    T::Num: BitLength,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Exhaustive> Extend<T> for ExhaustiveSet<T>
+where
+    T::Num: BitLength,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T: Exhaustive> BitOr for ExhaustiveSet<T>
+where
+    T::Num: BitLength,
+{
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl<T: Exhaustive> BitAnd for ExhaustiveSet<T>
+where
+    T::Num: BitLength,
+{
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl<T: Exhaustive> Sub for ExhaustiveSet<T>
+where
+    T::Num: BitLength,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl<T: Exhaustive> Not for ExhaustiveSet<T>
+where
+    T::Num: BitLength,
+{
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl<T: Exhaustive> Default for ExhaustiveSet<T>
+where
+    T::Num: BitLength,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Exhaustive + Debug> Debug for ExhaustiveSet<T>
+where
+    T::Num: BitLength,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Exhaustive> PartialEq for ExhaustiveSet<T>
+where
+    T::Num: BitLength,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.bits == other.bits
+    }
+}
+
+impl<T: Exhaustive> Eq for ExhaustiveSet<T> where T::Num: BitLength {}
+
+impl<T: Exhaustive> Hash for ExhaustiveSet<T>
+where
+    T::Num: BitLength,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bits.hash(state);
+    }
+}
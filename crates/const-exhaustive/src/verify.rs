@@ -0,0 +1,143 @@
+use {crate::Exhaustive, core::fmt::Debug};
+
+/// Checks that the values of an [`Exhaustive`] type are consistent, failing
+/// the build if they are not.
+///
+/// This is intended for manual `unsafe impl`s of [`Exhaustive`], and checks
+/// that [`Exhaustive::ALL`] has no duplicate values. The type must have an
+/// inherent `const fn const_eq(&self, other: &Self) -> bool`, e.g. by
+/// deriving [`ConstEq`](crate::ConstEq).
+///
+/// The length of [`Exhaustive::ALL`] doesn't need to be checked, since it is a
+/// [`GenericArray`] of exactly [`Exhaustive::Num`] values.
+///
+/// This cannot check that [`Exhaustive::ALL`] contains *every* value of the
+/// type - that is still up to you. To also check [`Exhaustive::index`] and
+/// [`Exhaustive::from_index`], use [`debug_verify`] in a test.
+///
+/// This compares every pair of values, so it takes quadratic time to compile.
+///
+/// # Examples
+///
+/// ```
+/// use const_exhaustive::{ConstEq, Exhaustive, verify_exhaustive};
+///
+/// #[derive(Clone, Copy, Exhaustive, ConstEq)]
+/// enum Suit {
+///     Clubs,
+///     Diamonds,
+///     Hearts,
+///     Spades,
+/// }
+///
+/// verify_exhaustive!(Suit);
+/// ```
+///
+/// A duplicate value fails to compile:
+///
+/// ```compile_fail
+/// use const_exhaustive::{
+///     ConstEq, Exhaustive, generic_array::GenericArray, typenum, verify_exhaustive,
+/// };
+///
+/// #[derive(Clone, Copy, ConstEq)]
+/// struct UintUpTo2(u8);
+///
+/// unsafe impl Exhaustive for UintUpTo2 {
+///     type Num = typenum::U2;
+///
+///     const ALL: GenericArray<Self, Self::Num> = GenericArray::from_array([Self(0), Self(0)]);
+///
+///     fn index(self) -> usize {
+///         usize::from(self.0)
+///     }
+/// }
+///
+/// verify_exhaustive!(UintUpTo2);
+/// ```
+///
+/// [`GenericArray`]: generic_array::GenericArray
+#[macro_export]
+macro_rules! verify_exhaustive {
+    ($ty:ty $(,)?) => {
+        const _: () = {
+            let all = <$ty as $crate::Exhaustive>::ALL;
+            let all = all.as_slice();
+            let mut i = 0;
+            while i < all.len() {
+                let mut j = i + 1;
+                while j < all.len() {
+                    assert!(
+                        !all[i].const_eq(&all[j]),
+                        "`Exhaustive::ALL` contains duplicate values"
+                    );
+                    j += 1;
+                }
+                i += 1;
+            }
+        };
+    };
+}
+
+/// Checks that the [`Exhaustive`] impl of `T` is consistent, at runtime.
+///
+/// This is intended to be called in a test for manual `unsafe impl`s of
+/// [`Exhaustive`], and checks that:
+/// - [`Exhaustive::ALL`] has no duplicate values
+/// - [`Exhaustive::index`] of each value matches its position in
+///   [`Exhaustive::ALL`]
+/// - [`Exhaustive::from_index`] is the inverse of [`Exhaustive::index`], and
+///   returns [`None`] for an index past the last value
+///
+/// This cannot check that [`Exhaustive::ALL`] contains *every* value of the
+/// type - that is still up to you.
+///
+/// Unlike [`verify_exhaustive!`], this works for any `T: PartialEq`, and runs
+/// in linear time.
+///
+/// # Panics
+///
+/// Panics with a description of the first problem found, if any.
+///
+/// # Examples
+///
+/// ```
+/// use const_exhaustive::{Exhaustive, debug_verify};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+/// enum Suit {
+///     Clubs,
+///     Diamonds,
+///     Hearts,
+///     Spades,
+/// }
+///
+/// debug_verify::<Suit>();
+/// debug_verify::<[Option<Suit>; 2]>();
+/// ```
+pub fn debug_verify<T: Exhaustive + PartialEq + Debug>() {
+    let all = T::ALL;
+    let all = all.as_slice();
+    for (i, value) in all.iter().enumerate() {
+        let index = value.index();
+        // if there is a duplicate, `index` will only match one of its positions
+        assert!(
+            index == i || all.get(index) != Some(value),
+            "`Exhaustive::ALL` contains {value:?} at both {index} and {i}"
+        );
+        assert_eq!(
+            i, index,
+            "`Exhaustive::index` of {value:?} does not match its position in `Exhaustive::ALL`"
+        );
+        assert_eq!(
+            Some(*value),
+            T::from_index(i),
+            "`Exhaustive::from_index({i})` does not match `Exhaustive::ALL`"
+        );
+    }
+    assert_eq!(
+        None,
+        T::from_index(all.len()),
+        "`Exhaustive::from_index` returned a value past the end of `Exhaustive::ALL`"
+    );
+}
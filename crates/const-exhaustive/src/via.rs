@@ -0,0 +1,268 @@
+use {
+    crate::{Exhaustive, Finite, Samples, array::map, count_of},
+    core::{
+        cmp::Ordering,
+        fmt,
+        hash::{Hash, Hasher},
+        marker::PhantomData,
+    },
+    generic_array::{ArrayLength, GenericArray},
+};
+
+/// Provides all values of `T`, for when `T` can't implement [`Exhaustive`]
+/// itself.
+///
+/// Because of the orphan rules, you can't implement [`Exhaustive`] on a type
+/// from another crate. Instead, you can implement this trait on a local type,
+/// and use that type with `#[exhaustive(with = ..)]` on any field of type `T`,
+/// or wrap `T` in [`Via`].
+///
+/// This trait can also be derived, by copying the definition of `T` into a
+/// local type, and using `#[derive(Exhaustive)]` with
+/// `#[exhaustive(remote = ..)]` on it.
+///
+/// The items of this trait have the same meaning as the items of
+/// [`Exhaustive`], but they describe `T` rather than `Self`.
+///
+/// # Safety
+///
+/// All possible values of `T`, as representable in memory, must be present in
+/// [`ExhaustiveVia::ALL`].
+///
+/// # Examples
+///
+/// ```
+/// use const_exhaustive::{Exhaustive, ExhaustiveVia, generic_array::GenericArray, typenum};
+///
+/// // pretend that this is in another crate
+/// mod other {
+///     #[derive(Debug, Clone, Copy, PartialEq)]
+///     pub enum Mode {
+///         Fast,
+///         Slow,
+///     }
+/// }
+///
+/// use other::Mode;
+///
+/// struct ModeDef;
+///
+/// unsafe impl ExhaustiveVia<Mode> for ModeDef {
+///     type Num = typenum::U2;
+///
+///     const ALL: GenericArray<Mode, Self::Num> =
+///         GenericArray::from_array([Mode::Fast, Mode::Slow]);
+///
+///     fn index(value: Mode) -> usize {
+///         match value {
+///             Mode::Fast => 0,
+///             Mode::Slow => 1,
+///         }
+///     }
+/// }
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+/// struct Config {
+///     #[exhaustive(with = ModeDef)]
+///     mode: Mode,
+///     verbose: bool,
+/// }
+///
+/// assert_eq!(
+///     [
+///         Config {
+///             mode: Mode::Fast,
+///             verbose: false
+///         },
+///         Config {
+///             mode: Mode::Fast,
+///             verbose: true
+///         },
+///         Config {
+///             mode: Mode::Slow,
+///             verbose: false
+///         },
+///         Config {
+///             mode: Mode::Slow,
+///             verbose: true
+///         },
+///     ],
+///     Config::ALL.as_slice(),
+/// );
+/// ```
+pub unsafe trait ExhaustiveVia<T: Copy> {
+    /// Number of values that may exist of `T`.
+    ///
+    /// See [`Exhaustive::Num`](crate::Exhaustive::Num).
+    type Num: ArrayLength<ArrayType<T>: Copy>;
+
+    /// All values of `T`.
+    ///
+    /// See [`Exhaustive::ALL`](crate::Exhaustive::ALL).
+    const ALL: GenericArray<T, Self::Num>;
+
+    /// Gets the position of `value` in [`ExhaustiveVia::ALL`].
+    ///
+    /// See [`Exhaustive::index`](crate::Exhaustive::index).
+    fn index(value: T) -> usize;
+
+    /// Gets the value at position `index` in [`ExhaustiveVia::ALL`].
+    ///
+    /// See [`Exhaustive::from_index`](crate::Exhaustive::from_index).
+    #[must_use]
+    fn from_index(index: usize) -> Option<T> {
+        Self::ALL.as_slice().get(index).copied()
+    }
+}
+
+/// Wrapper around a `T`, which implements [`Exhaustive`] using the values from
+/// `R`.
+///
+/// This lets you use a type which only has an [`ExhaustiveVia`] impl, such as
+/// a type from another crate, wherever an [`Exhaustive`] bound is required.
+///
+/// # Examples
+///
+/// ```
+/// use const_exhaustive::{Exhaustive, ExhaustiveMap, Via};
+///
+/// // pretend that this is in another crate
+/// mod other {
+///     #[derive(Debug, Clone, Copy, PartialEq)]
+///     pub enum Mode {
+///         Fast,
+///         Slow,
+///     }
+/// }
+///
+/// #[derive(Exhaustive)]
+/// #[exhaustive(remote = other::Mode)]
+/// #[expect(dead_code, reason = "only used to derive `ExhaustiveVia`")]
+/// enum ModeDef {
+///     Fast,
+///     Slow,
+/// }
+///
+/// type Mode = Via<other::Mode, ModeDef>;
+///
+/// assert_eq!(
+///     [other::Mode::Fast, other::Mode::Slow],
+///     Mode::iter().map(Mode::get).collect::<Vec<_>>().as_slice(),
+/// );
+///
+/// let speeds = ExhaustiveMap::<Mode, u32>::from_fn(|mode| match mode.get() {
+///     other::Mode::Fast => 100,
+///     other::Mode::Slow => 10,
+/// });
+/// assert_eq!(100, speeds[Mode::new(other::Mode::Fast)]);
+/// ```
+pub struct Via<T, R> {
+    value: T,
+    _marker: PhantomData<fn() -> R>,
+}
+
+impl<T, R> Via<T, R> {
+    /// Wraps a value.
+    #[must_use]
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Gets a reference to the wrapped value.
+    #[must_use]
+    pub const fn as_inner(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: Copy, R> Via<T, R> {
+    /// Gets the wrapped value.
+    #[must_use]
+    pub const fn get(self) -> T {
+        self.value
+    }
+}
+
+impl<T: Clone, R> Clone for Via<T, R> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<T: Copy, R> Copy for Via<T, R> {}
+
+impl<T: fmt::Debug, R> fmt::Debug for Via<T, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Via").field(&self.value).finish()
+    }
+}
+
+impl<T: PartialEq, R> PartialEq for Via<T, R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq, R> Eq for Via<T, R> {}
+
+impl<T: PartialOrd, R> PartialOrd for Via<T, R> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: Ord, R> Ord for Via<T, R> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T: Hash, R> Hash for Via<T, R> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+unsafe impl<T: Copy, R: ExhaustiveVia<T>> Exhaustive for Via<T, R>
+where
+    R::Num: ArrayLength<ArrayType<Self>: Copy>,
+{
+    type Num = R::Num;
+
+    const ALL: GenericArray<Self, Self::Num> = map!(R::ALL, |t| Self::new(t));
+
+    fn index(self) -> usize {
+        R::index(self.value)
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        R::from_index(index).map(Self::new)
+    }
+}
+
+impl<T: Copy, R: ExhaustiveVia<T>> Samples for Via<T, R>
+where
+    R::Num: ArrayLength<ArrayType<Self>: Copy>,
+{
+    type Num = <Self as Exhaustive>::Num;
+
+    const SAMPLES: GenericArray<Self, Self::Num> = Self::ALL;
+}
+
+unsafe impl<T: Copy, R: ExhaustiveVia<T>> Finite for Via<T, R>
+where
+    R::Num: ArrayLength<ArrayType<Self>: Copy>,
+{
+    const COUNT: u128 = count_of::<Self>();
+
+    fn rank(self) -> u128 {
+        R::index(self.value) as u128
+    }
+
+    fn unrank(rank: u128) -> Option<Self> {
+        usize::try_from(rank).ok().and_then(Self::from_index)
+    }
+}
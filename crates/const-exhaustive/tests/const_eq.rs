@@ -0,0 +1,169 @@
+#![expect(missing_docs, reason = "test module")]
+
+use {
+    const_exhaustive::{ConstEq, Exhaustive, ExhaustiveSet, RangedLenU8, const_eq},
+    core::{convert::Infallible, marker::PhantomData},
+};
+
+mod prelude {
+    pub use const_exhaustive as exhaustive;
+}
+
+// not `core::option::Option`
+mod other {
+    use const_exhaustive::Exhaustive;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Exhaustive)]
+    pub struct Option<T> {
+        pub value: T,
+    }
+
+    impl Option<bool> {
+        #[expect(
+            clippy::trivially_copy_pass_by_ref,
+            reason = "same signature as a derived `const_eq`"
+        )]
+        pub const fn const_eq(&self, other: &Self) -> bool {
+            self.value == other.value
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Exhaustive, ConstEq)]
+enum Color {
+    Red,
+    Green,
+    Blue(bool),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Exhaustive, ConstEq)]
+struct Compound {
+    maybe: Option<Result<bool, Color>>,
+    pair: (bool, [bool; 2]),
+    dice: RangedLenU8<1, 2>,
+    unit: (),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Exhaustive, ConstEq)]
+enum Special<T> {
+    Never(Infallible),
+    Phantom(PhantomData<T>),
+    Set(ExhaustiveSet<Color>),
+}
+
+type Flag = bool;
+
+#[expect(
+    clippy::trivially_copy_pass_by_ref,
+    reason = "signature required by `const_eq = ..`"
+)]
+const fn flag_eq(a: &Flag, b: &Flag) -> bool {
+    const_eq!(bool, a, b)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Exhaustive, ConstEq)]
+#[exhaustive(crate = "crate::prelude::exhaustive")]
+struct Written {
+    other: other::Option<bool>,
+    full: core::option::Option<bool>,
+    #[exhaustive(const_eq = flag_eq)]
+    flag: Flag,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ConstEq)]
+struct Floats(f32, [f64; 2]);
+
+#[derive(Debug, Clone, Copy, PartialEq, Exhaustive, ConstEq)]
+struct Empty;
+
+#[derive(Debug, Clone, Copy, PartialEq, Exhaustive, ConstEq)]
+enum Uninhabited {}
+
+#[track_caller]
+fn assert_matches_eq<T: Exhaustive + PartialEq>(const_eq: impl Fn(&T, &T) -> bool) {
+    for a in T::iter() {
+        for b in T::iter() {
+            assert_eq!(a == b, const_eq(&a, &b));
+        }
+    }
+}
+
+#[test]
+fn matches_eq() {
+    assert_matches_eq::<Color>(Color::const_eq);
+    assert_matches_eq::<Compound>(Compound::const_eq);
+    assert_matches_eq::<Special<bool>>(Special::const_eq);
+    assert_matches_eq::<Written>(Written::const_eq);
+    assert_matches_eq::<Empty>(Empty::const_eq);
+    assert_matches_eq::<Uninhabited>(Uninhabited::const_eq);
+}
+
+#[test]
+fn const_lookup() {
+    const fn index_of(color: Color) -> usize {
+        let mut i = 0;
+        while !Color::ALL.as_slice()[i].const_eq(&color) {
+            i += 1;
+        }
+        i
+    }
+
+    const BLUE: usize = index_of(Color::Blue(true));
+    assert_eq!(3, BLUE);
+}
+
+#[test]
+fn const_dedup() {
+    const fn all_distinct() -> bool {
+        let all = Special::<bool>::ALL;
+        let all = all.as_slice();
+        let mut i = 0;
+        while i < all.len() {
+            let mut j = i + 1;
+            while j < all.len() {
+                if all[i].const_eq(&all[j]) {
+                    return false;
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    const _: () = assert!(all_distinct());
+}
+
+#[test]
+fn floats() {
+    const NAN: Floats = Floats(f32::NAN, [0.0, 1.0]);
+    const ZERO: Floats = Floats(0.0, [-0.0, 1.0]);
+
+    // same as `==`
+    assert_eq!(NAN == NAN, NAN.const_eq(&NAN));
+    assert_eq!(
+        ZERO == Floats(-0.0, [0.0, 1.0]),
+        ZERO.const_eq(&Floats(-0.0, [0.0, 1.0]))
+    );
+    assert!(!ZERO.const_eq(&Floats(0.0, [0.0, 2.0])));
+}
+
+#[test]
+fn macro_matches_eq() {
+    assert_matches_eq::<bool>(|a, b| const_eq!(bool, a, b));
+    assert_matches_eq::<Option<(bool, [bool; 2])>>(|a, b| {
+        const_eq!(Option<(bool, [bool; 2])>, a, b)
+    });
+    assert_matches_eq::<Result<Color, bool>>(|a, b| const_eq!(Result<Color, bool>, a, b,));
+}
+
+#[test]
+fn macro_in_const() {
+    const fn is_origin(point: Option<(i32, i32)>) -> bool {
+        const_eq!(Option<(i32, i32)>, &point, &Some((0, 0)))
+    }
+
+    const _: () = assert!(is_origin(Some((0, 0))));
+    const _: () = assert!(!is_origin(Some((0, 1))));
+    const _: () = assert!(!is_origin(None));
+}
@@ -0,0 +1,143 @@
+#![expect(missing_docs, reason = "test module")]
+#![cfg(feature = "alloc")]
+
+use {
+    const_exhaustive::{Exhaustive, ExhaustiveProduct, pairwise, t_wise},
+    core::{cmp::Ordering, convert::Infallible, fmt::Debug},
+};
+
+// index of each field's value in `value`
+fn fields<T: ExhaustiveProduct>(value: T) -> Vec<usize> {
+    let mut index = value.index();
+    let mut fields = T::RADICES
+        .iter()
+        .rev()
+        .map(|radix| {
+            let field = index % radix;
+            index /= radix;
+            field
+        })
+        .collect::<Vec<_>>();
+    fields.reverse();
+    fields
+}
+
+// every combination of values of every `strength` fields appears in `values`
+#[track_caller]
+fn assert_covers<T: ExhaustiveProduct + Debug>(values: &[T], strength: usize) {
+    let radices = T::RADICES;
+    let values = values.iter().copied().map(fields).collect::<Vec<_>>();
+    for subset in
+        (0..1usize << radices.len()).filter(|subset| subset.count_ones() as usize == strength)
+    {
+        let subset_fields = (0..radices.len())
+            .filter(|field| subset & (1 << field) != 0)
+            .collect::<Vec<_>>();
+        let combinations = subset_fields
+            .iter()
+            .map(|&field| radices[field])
+            .product::<usize>();
+        for mut combination in 0..combinations {
+            let mut value = vec![0; radices.len()];
+            for &field in subset_fields.iter().rev() {
+                value[field] = combination % radices[field];
+                combination /= radices[field];
+            }
+            assert!(
+                values.iter().any(|covering| subset_fields
+                    .iter()
+                    .all(|&field| covering[field] == value[field])),
+                "fields {value:?} of subset {subset:b} are not covered"
+            );
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+enum Browser {
+    Firefox,
+    Chrome,
+    Safari,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+struct Config {
+    browser: Browser,
+    dark_mode: bool,
+    #[exhaustive(unsafe(values = [1, 2, 4]))]
+    scale: u8,
+    #[exhaustive(unsafe(fixed = "en"))]
+    locale: &'static str,
+    mobile: Option<bool>,
+}
+
+#[test]
+fn pairs() {
+    type Tuple = (bool, Option<bool>, Ordering, bool, Browser);
+
+    let tuples = pairwise::<Tuple>();
+    assert_covers(&tuples, 2);
+    assert!(tuples.len() < Tuple::ALL.len());
+
+    let configs = pairwise::<Config>();
+    assert_covers(&configs, 2);
+    assert!(configs.len() < Config::ALL.len());
+
+    let flags = pairwise::<[bool; 6]>();
+    assert_covers(&flags, 2);
+    assert!(flags.len() <= 8);
+}
+
+#[test]
+fn many_values() {
+    // `ALL` would have over a million values, which is too many to evaluate
+    let flags = pairwise::<[bool; 20]>();
+    assert_covers(&flags, 2);
+    assert!(flags.len() < 32);
+}
+
+#[test]
+fn higher_strength() {
+    let flags = t_wise::<[bool; 6], 3>();
+    assert_covers(&flags, 3);
+    assert!(flags.len() < 64);
+
+    let configs = t_wise::<Config, 3>();
+    assert_covers(&configs, 3);
+    assert!(configs.len() < Config::ALL.len());
+
+    let single = t_wise::<(bool, Browser), 1>();
+    assert_covers(&single, 1);
+    assert_eq!(3, single.len());
+
+    assert_eq!(1, t_wise::<(bool, Browser), 0>().len());
+}
+
+#[test]
+fn all_values() {
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    struct Unit;
+
+    assert_eq!(
+        <(bool, Browser)>::ALL.as_slice(),
+        pairwise::<(bool, Browser)>()
+    );
+    assert_eq!(Config::ALL.as_slice(), t_wise::<Config, 5>());
+    assert_eq!([Unit], pairwise::<Unit>().as_slice());
+    assert_eq!([()], pairwise::<()>().as_slice());
+    assert!(pairwise::<(bool, Infallible, bool)>().is_empty());
+}
+
+#[test]
+fn deterministic() {
+    assert_eq!(pairwise::<Config>(), pairwise::<Config>());
+    assert_eq!(
+        [
+            [false, false, false],
+            [false, true, true],
+            [true, false, true],
+            [true, true, false],
+        ],
+        pairwise::<[bool; 3]>().as_slice()
+    );
+}
@@ -0,0 +1,47 @@
+#![expect(missing_docs, reason = "test module")]
+
+use const_exhaustive::Exhaustive;
+
+mod prelude {
+    pub use const_exhaustive as exhaustive;
+}
+
+#[test]
+fn crate_path() {
+    #[derive(Debug, Clone, Copy, PartialEq, prelude::exhaustive::Exhaustive)]
+    #[exhaustive(crate = prelude::exhaustive)]
+    struct Struct(bool);
+
+    #[derive(Debug, Clone, Copy, PartialEq, prelude::exhaustive::Exhaustive)]
+    #[exhaustive(crate = "crate::prelude::exhaustive")]
+    enum Enum {
+        A,
+        B(Struct),
+    }
+
+    assert_eq!([Struct(false), Struct(true)], Struct::ALL.as_slice());
+    assert_eq!(
+        [Enum::A, Enum::B(Struct(false)), Enum::B(Struct(true))],
+        Enum::ALL.as_slice(),
+    );
+    assert_eq!(Some(Enum::B(Struct(true))), Enum::from_index(2));
+}
+
+#[test]
+#[allow(dead_code, reason = "types only exist to check the crate path")]
+const fn crate_path_hygiene() {
+    // a local `const_exhaustive` which has none of the items that the derive
+    // needs, so all items must go through the crate path we give it
+    mod const_exhaustive {}
+
+    #[derive(Clone, Copy, prelude::exhaustive::Exhaustive)]
+    #[exhaustive(crate = prelude::exhaustive)]
+    struct Struct(bool, Option<bool>);
+
+    #[derive(Clone, Copy, prelude::exhaustive::Exhaustive)]
+    #[exhaustive(crate = prelude::exhaustive)]
+    enum Enum {
+        A(Struct),
+        B { a: bool },
+    }
+}
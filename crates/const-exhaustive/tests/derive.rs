@@ -2,12 +2,18 @@
 
 use {
     const_exhaustive::Exhaustive,
-    core::{fmt::Debug, marker::PhantomData},
+    core::{convert::Infallible, fmt::Debug, marker::PhantomData},
 };
 
 fn assert_all<T: Exhaustive + Debug + PartialEq>(values: impl IntoIterator<Item = T>) {
     let values = values.into_iter().collect::<Vec<_>>();
     assert_eq!(values.as_slice(), T::ALL.as_slice());
+
+    for (index, value) in values.iter().enumerate() {
+        assert_eq!(index, value.index());
+        assert_eq!(Some(*value), T::from_index(index));
+    }
+    assert_eq!(None, T::from_index(values.len()));
 }
 
 #[test]
@@ -389,3 +395,339 @@ fn generic() {
         ]
     );
 }
+
+#[test]
+#[expect(clippy::items_after_statements, reason = "easier to read")]
+fn generic_enum_first_variant_concrete() {
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    enum Event<T> {
+        Tick,
+        Input(T),
+    }
+    assert_all([Event::<Infallible>::Tick]);
+    assert_all([Event::Tick, Event::Input(false), Event::Input(true)]);
+
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    enum Mixed<A, B> {
+        A(bool),
+        B { a: bool, b: A },
+        C(Option<B>, A),
+    }
+    assert_all([
+        Mixed::<(), ()>::A(false),
+        Mixed::A(true),
+        Mixed::B { a: false, b: () },
+        Mixed::B { a: true, b: () },
+        Mixed::C(None, ()),
+        Mixed::C(Some(()), ()),
+    ]);
+
+    // nested derived generic types
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    struct Outer<T> {
+        flag: bool,
+        event: Event<T>,
+    }
+    assert_all([
+        Outer {
+            flag: false,
+            event: Event::Tick,
+        },
+        Outer {
+            flag: false,
+            event: Event::Input(()),
+        },
+        Outer {
+            flag: true,
+            event: Event::Tick,
+        },
+        Outer {
+            flag: true,
+            event: Event::Input(()),
+        },
+    ]);
+}
+
+#[test]
+#[expect(clippy::items_after_statements, reason = "easier to read")]
+fn bound() {
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    #[exhaustive(bound = "T: Exhaustive")]
+    struct Container<T> {
+        value: T,
+    }
+    assert_all([Container { value: false }, Container { value: true }]);
+
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    #[exhaustive(bound = "")]
+    enum Empty {
+        A(bool),
+    }
+    assert_all([Empty::A(false), Empty::A(true)]);
+
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    struct Field<T, U> {
+        #[exhaustive(bound = "T: Exhaustive + Default")]
+        a: T,
+        #[exhaustive(bound = "U: Exhaustive, U: Debug")]
+        b: U,
+        #[exhaustive(bound = "")]
+        c: (),
+    }
+    assert_all([
+        Field {
+            a: false,
+            b: (),
+            c: (),
+        },
+        Field {
+            a: true,
+            b: (),
+            c: (),
+        },
+    ]);
+}
+
+#[test]
+fn index() {
+    fn assert_index<T: Exhaustive + Debug + PartialEq>() {
+        for (index, value) in T::ALL.as_slice().iter().enumerate() {
+            assert_eq!(index, value.index());
+            assert_eq!(Some(*value), T::from_index(index));
+        }
+        assert_eq!(None, T::from_index(T::ALL.as_slice().len()));
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    enum Empty {}
+
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    struct Struct {
+        index: bool,
+        b: Option<bool>,
+        c: (),
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    enum Enum {
+        Unit,
+        Tuple(bool, Option<bool>),
+        Named { index: bool, b: [bool; 2] },
+        EndUnit,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    enum Generic<T> {
+        A(T),
+        B { a: T, b: T },
+    }
+
+    assert_index::<Empty>();
+    assert_index::<Struct>();
+    assert_index::<Enum>();
+    assert_index::<Generic<bool>>();
+    assert_index::<Generic<Option<Enum>>>();
+
+    assert_eq!(
+        Some(Enum::Named {
+            index: true,
+            b: [false, true]
+        }),
+        Enum::from_index(12),
+    );
+}
+
+#[test]
+#[expect(clippy::items_after_statements, reason = "easier to read")]
+fn values() {
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    struct Range {
+        #[exhaustive(unsafe(values = 0..3))]
+        a: u8,
+        #[exhaustive(unsafe(values = -1..=0))]
+        b: i32,
+    }
+    assert_all([
+        Range { a: 0, b: -1 },
+        Range { a: 0, b: 0 },
+        Range { a: 1, b: -1 },
+        Range { a: 1, b: 0 },
+        Range { a: 2, b: -1 },
+        Range { a: 2, b: 0 },
+    ]);
+
+    const WEIGHTS: [u16; 3] = [8, 1, 300];
+
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    enum Array {
+        Flag(#[exhaustive(unsafe(values = ['x', 'y']))] char),
+        Weight(#[exhaustive(unsafe(values = WEIGHTS))] u16, bool),
+    }
+    assert_all([
+        Array::Flag('x'),
+        Array::Flag('y'),
+        Array::Weight(8, false),
+        Array::Weight(8, true),
+        Array::Weight(1, false),
+        Array::Weight(1, true),
+        Array::Weight(300, false),
+        Array::Weight(300, true),
+    ]);
+
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    enum Generic<T> {
+        A(#[exhaustive(unsafe(values = 1..=2))] u8, T),
+        B(T),
+    }
+    assert_all([Generic::A(1, ()), Generic::A(2, ()), Generic::B(())]);
+
+    // largest values which the offsets can represent
+    const LARGEST: u128 = u128::MAX >> 1;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    struct Large {
+        #[exhaustive(unsafe(values = 5_000_000_000..5_000_000_002))]
+        a: u64,
+        #[exhaustive(unsafe(values = LARGEST - 1..=LARGEST))]
+        b: u128,
+    }
+    assert_all([
+        Large {
+            a: 5_000_000_000,
+            b: LARGEST - 1,
+        },
+        Large {
+            a: 5_000_000_000,
+            b: LARGEST,
+        },
+        Large {
+            a: 5_000_000_001,
+            b: LARGEST - 1,
+        },
+        Large {
+            a: 5_000_000_001,
+            b: LARGEST,
+        },
+    ]);
+}
+
+#[test]
+#[expect(clippy::items_after_statements, reason = "easier to read")]
+fn fixed() {
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    struct Message {
+        #[exhaustive(unsafe(fixed = "message"))]
+        tag: &'static str,
+        urgent: bool,
+        #[exhaustive(unsafe(fixed = 2))]
+        version: u32,
+    }
+    assert_all([
+        Message {
+            tag: "message",
+            urgent: false,
+            version: 2,
+        },
+        Message {
+            tag: "message",
+            urgent: true,
+            version: 2,
+        },
+    ]);
+
+    const ORIGIN: (f32, f32) = (0.0, 0.0);
+
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    enum Shape<T> {
+        Point(#[exhaustive(unsafe(fixed = ORIGIN))] (f32, f32)),
+        Tagged(#[exhaustive(unsafe(fixed = 'x'))] char, T),
+    }
+    assert_all([
+        Shape::Point(ORIGIN),
+        Shape::Tagged('x', false),
+        Shape::Tagged('x', true),
+    ]);
+}
+
+#[test]
+#[expect(clippy::items_after_statements, reason = "easier to read")]
+fn with() {
+    use const_exhaustive::{ExhaustiveVia, generic_array::GenericArray, typenum};
+
+    // pretend that these are foreign types
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Mode {
+        Fast,
+        Slow,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Pair<T>(T, T);
+
+    struct ModeDef;
+
+    unsafe impl ExhaustiveVia<Mode> for ModeDef {
+        type Num = typenum::U2;
+
+        const ALL: GenericArray<Mode, Self::Num> =
+            GenericArray::from_array([Mode::Fast, Mode::Slow]);
+
+        fn index(value: Mode) -> usize {
+            match value {
+                Mode::Fast => 0,
+                Mode::Slow => 1,
+            }
+        }
+    }
+
+    // only pairs of the same value
+    struct SamePair;
+
+    unsafe impl ExhaustiveVia<Pair<bool>> for SamePair {
+        type Num = typenum::U2;
+
+        const ALL: GenericArray<Pair<bool>, Self::Num> =
+            GenericArray::from_array([Pair(false, false), Pair(true, true)]);
+
+        fn index(value: Pair<bool>) -> usize {
+            usize::from(value.0)
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    struct Config {
+        #[exhaustive(with = ModeDef)]
+        mode: Mode,
+        verbose: bool,
+    }
+    assert_all([
+        Config {
+            mode: Mode::Fast,
+            verbose: false,
+        },
+        Config {
+            mode: Mode::Fast,
+            verbose: true,
+        },
+        Config {
+            mode: Mode::Slow,
+            verbose: false,
+        },
+        Config {
+            mode: Mode::Slow,
+            verbose: true,
+        },
+    ]);
+
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    enum Generic<T> {
+        A(#[exhaustive(with = "ModeDef")] Mode),
+        B(#[exhaustive(with = SamePair)] Pair<bool>, T),
+    }
+    assert_all([
+        Generic::A(Mode::Fast),
+        Generic::A(Mode::Slow),
+        Generic::B(Pair(false, false), ()),
+        Generic::B(Pair(true, true), ()),
+    ]);
+}
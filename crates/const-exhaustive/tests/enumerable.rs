@@ -0,0 +1,197 @@
+#![expect(missing_docs, reason = "test module")]
+
+use {
+    const_exhaustive::{Enumerable, Exhaustive, Finite},
+    core::{cell::Cell, fmt::Debug},
+};
+
+#[track_caller]
+fn assert_all<T: Enumerable + Debug + PartialEq>(values: impl IntoIterator<Item = T>) {
+    let values = values.into_iter().collect::<Vec<_>>();
+    assert_eq!(values.len(), T::LEN);
+    for (index, value) in values.iter().enumerate() {
+        assert_eq!(Some(value), T::nth(index).as_ref());
+    }
+    assert_eq!(None, T::nth(values.len()));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+enum Level {
+    Low,
+    High,
+}
+
+// not `Copy`, and has drop glue
+#[derive(Debug, PartialEq, Enumerable)]
+struct Tag {
+    #[exhaustive(unsafe(fixed = String::from("tag")))]
+    name: String,
+    level: Level,
+}
+
+fn tag(level: Level) -> Tag {
+    Tag {
+        name: String::from("tag"),
+        level,
+    }
+}
+
+#[test]
+fn exhaustive() {
+    assert_all([false, true]);
+    assert_all([None, Some(Level::Low), Some(Level::High)]);
+    assert_all(<(bool, Level)>::iter());
+    assert_all(<[Option<bool>; 3]>::iter());
+}
+
+#[test]
+fn with_finite() {
+    // both traits are in scope, and their constants don't conflict
+    assert_eq!(3, Option::<bool>::LEN);
+    assert_eq!(3, Option::<bool>::COUNT);
+}
+
+#[test]
+fn derive_struct() {
+    #[derive(Debug, PartialEq, Enumerable)]
+    struct Unit;
+
+    #[derive(Debug, PartialEq, Enumerable)]
+    struct Tuple(Tag, bool);
+
+    assert_all([Unit]);
+    assert_all([tag(Level::Low), tag(Level::High)]);
+    assert_all([
+        Tuple(tag(Level::Low), false),
+        Tuple(tag(Level::Low), true),
+        Tuple(tag(Level::High), false),
+        Tuple(tag(Level::High), true),
+    ]);
+}
+
+#[test]
+fn derive_enum() {
+    #[derive(Debug, PartialEq, Enumerable)]
+    enum Empty {}
+
+    #[derive(Debug, PartialEq, Enumerable)]
+    enum Message<T> {
+        Ping,
+        Tagged(Tag),
+        Value { value: T, urgent: bool },
+    }
+
+    assert_all::<Empty>([]);
+    assert_all([
+        Message::Ping,
+        Message::Tagged(tag(Level::Low)),
+        Message::Tagged(tag(Level::High)),
+        Message::Value {
+            value: Level::Low,
+            urgent: false,
+        },
+        Message::Value {
+            value: Level::Low,
+            urgent: true,
+        },
+        Message::Value {
+            value: Level::High,
+            urgent: false,
+        },
+        Message::Value {
+            value: Level::High,
+            urgent: true,
+        },
+    ]);
+}
+
+#[test]
+fn same_order_as_exhaustive() {
+    // already `Enumerable`, since it is `Exhaustive`
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    enum Both {
+        A,
+        B(bool, Level),
+        C { a: Option<bool> },
+    }
+
+    #[derive(Debug, PartialEq, Enumerable)]
+    #[exhaustive(crate = "const_exhaustive")]
+    struct NotCopy(Both);
+
+    assert_all(Both::iter());
+    assert_all(Both::iter().map(NotCopy));
+}
+
+#[test]
+#[expect(clippy::items_after_statements, reason = "easier to read")]
+fn derive_attributes() {
+    #[derive(Debug, PartialEq, Enumerable)]
+    struct Values {
+        #[exhaustive(unsafe(values = 1..=2))]
+        a: u8,
+        #[exhaustive(unsafe(values = [10, 20]))]
+        b: i32,
+        #[exhaustive(unsafe(fixed = Cell::new(5)))]
+        c: Cell<u32>,
+    }
+    assert_all([
+        Values {
+            a: 1,
+            b: 10,
+            c: Cell::new(5),
+        },
+        Values {
+            a: 1,
+            b: 20,
+            c: Cell::new(5),
+        },
+        Values {
+            a: 2,
+            b: 10,
+            c: Cell::new(5),
+        },
+        Values {
+            a: 2,
+            b: 20,
+            c: Cell::new(5),
+        },
+    ]);
+
+    #[derive(Debug, PartialEq, Enumerable)]
+    #[exhaustive(order = "reverse")]
+    enum Reversed {
+        A,
+        B(Tag),
+    }
+    assert_all([
+        Reversed::B(tag(Level::High)),
+        Reversed::B(tag(Level::Low)),
+        Reversed::A,
+    ]);
+
+    #[derive(Debug, PartialEq, Enumerable)]
+    #[exhaustive(order = "reverse")]
+    enum Empty {}
+    assert_all::<Empty>([]);
+}
+
+#[test]
+fn drop() {
+    thread_local! {
+        static DROPPED: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[derive(Debug, PartialEq, Enumerable)]
+    struct Guard(Level);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            DROPPED.set(DROPPED.get() + 1);
+        }
+    }
+
+    assert_eq!(Some(Guard(Level::High)), Guard::nth(1));
+    assert_eq!(None, Guard::nth(2));
+    assert_eq!(2, DROPPED.get());
+}
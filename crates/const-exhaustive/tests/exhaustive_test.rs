@@ -0,0 +1,114 @@
+#![expect(missing_docs, reason = "test module")]
+
+use {
+    const_exhaustive::{Exhaustive, exhaustive_test},
+    core::sync::atomic::{AtomicUsize, Ordering},
+};
+
+mod prelude {
+    pub use const_exhaustive as exhaustive;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Exhaustive)]
+enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    const fn rotate_cw(self) -> Self {
+        match self {
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North,
+        }
+    }
+
+    const fn rotate_ccw(self) -> Self {
+        match self {
+            Self::North => Self::West,
+            Self::East => Self::North,
+            Self::South => Self::East,
+            Self::West => Self::South,
+        }
+    }
+}
+
+#[exhaustive_test]
+fn rotate_roundtrips(d: Direction, flip: bool) {
+    let d = if flip { d.rotate_cw() } else { d };
+    assert_eq!(d, d.rotate_cw().rotate_ccw());
+}
+
+#[exhaustive_test]
+fn no_args() {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    assert_eq!(0, CALLS.fetch_add(1, Ordering::Relaxed));
+}
+
+#[exhaustive_test]
+fn patterns((a, b): (bool, bool), mut c: Option<bool>) {
+    let before = c;
+    c = c.map(|c| c ^ a ^ b);
+    assert_eq!(before.is_some(), c.is_some());
+}
+
+// `ALL` of this would have over a million values
+#[exhaustive_test]
+fn many_values(flags: [bool; 20]) {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    let calls = CALLS.fetch_add(1, Ordering::Relaxed);
+    assert_eq!(calls, flags.index());
+}
+
+#[derive(Debug, Clone, Copy, Exhaustive)]
+struct Point {
+    x: bool,
+    y: bool,
+}
+
+#[exhaustive_test]
+fn braces(Point { x, y }: Point) {
+    assert_eq!(x && y, !(!x || !y));
+}
+
+#[exhaustive_test]
+#[should_panic(
+    expected = "failed on input 5: Point { x, .. } = Point { x: true, y: false }, y = true\nboth"
+)]
+fn reports_braces(Point { x, .. }: Point, y: bool) {
+    assert!(!(x && y), "both");
+}
+
+#[exhaustive_test(crate = "crate::prelude::exhaustive")]
+fn crate_path(d: Direction) {
+    assert_eq!(d, d.rotate_cw().rotate_cw().rotate_cw().rotate_cw());
+}
+
+#[exhaustive_test]
+#[should_panic(expected = "failed on input 7: d = West, flip = true\nrotated too far")]
+fn reports_input(d: Direction, flip: bool) {
+    assert!(!(d == Direction::West && flip), "rotated too far");
+}
+
+#[exhaustive_test]
+#[should_panic(expected = "failed on input 1: n = Some(false)\nassertion failed")]
+fn reports_first_failure(n: Option<bool>) {
+    assert!(n != Some(false));
+    assert!(n != Some(true), "should stop at the first failure");
+}
+
+// shadowed by a local in the generated test
+#[exhaustive_test]
+fn value(index: bool) {
+    let _ = index;
+}
+
+#[exhaustive_test]
+#[ignore = "only checks that attributes are kept"]
+fn keeps_attributes(a: bool) {
+    assert!(a);
+}
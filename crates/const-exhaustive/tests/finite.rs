@@ -0,0 +1,258 @@
+#![expect(missing_docs, reason = "test module")]
+
+use {
+    const_exhaustive::{
+        ColexOrder, Exhaustive, ExhaustiveSet, Finite, GrayOrder, RangedLenI8, RangedLenU8,
+        RangedLenU32, ReverseOrder, exhaustive_newtype, typenum::Unsigned,
+    },
+    core::{
+        cmp::{Ordering, Reverse},
+        convert::Infallible,
+        fmt::{Alignment, Debug},
+        marker::{PhantomData, PhantomPinned},
+        mem::ManuallyDrop,
+        num::{FpCategory, Saturating, Wrapping},
+        ops::{Bound, ControlFlow},
+        task::Poll,
+    },
+};
+
+// `Finite` must rank values in the same order as `Exhaustive::ALL`
+#[track_caller]
+fn assert_same<T: Exhaustive + Finite + Debug + PartialEq>() {
+    assert_eq!(T::Num::USIZE as u128, T::COUNT);
+    for (index, value) in T::iter().enumerate() {
+        assert_eq!(index as u128, value.rank());
+        assert_eq!(Some(value), T::unrank(index as u128));
+    }
+    assert_eq!(None, T::unrank(T::COUNT));
+}
+
+#[track_caller]
+fn assert_roundtrip<T: Finite + Debug + PartialEq>(ranks: impl IntoIterator<Item = u128>) {
+    for rank in ranks {
+        let value = T::unrank(rank).expect("rank should be in bounds");
+        assert_eq!(rank, value.rank());
+    }
+    assert_eq!(None, T::unrank(T::COUNT));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Exhaustive)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+#[test]
+fn core_types() {
+    assert_same::<Infallible>();
+    assert_same::<()>();
+    assert_same::<PhantomPinned>();
+    assert_same::<PhantomData<str>>();
+    assert_same::<bool>();
+    assert_same::<Ordering>();
+    assert_same::<Alignment>();
+    assert_same::<FpCategory>();
+    assert_same::<Reverse<Option<bool>>>();
+    assert_same::<Wrapping<bool>>();
+    assert_same::<Saturating<bool>>();
+    assert_same::<ManuallyDrop<bool>>();
+    assert_same::<Option<Option<bool>>>();
+    assert_same::<Result<bool, Ordering>>();
+    assert_same::<Bound<bool>>();
+    assert_same::<ControlFlow<bool, Option<bool>>>();
+    assert_same::<Poll<bool>>();
+    assert_same::<[Option<bool>; 0]>();
+    assert_same::<[Option<bool>; 4]>();
+    assert_same::<(bool, Ordering, Option<bool>)>();
+    assert_same::<(Infallible, bool)>();
+}
+
+#[cfg(feature = "small-ints")]
+#[test]
+fn small_ints() {
+    use core::num::NonZero;
+
+    assert_same::<u8>();
+    assert_same::<i8>();
+    assert_same::<NonZero<u8>>();
+    assert_same::<NonZero<i8>>();
+    assert_eq!(1 << 64, <[u8; 8]>::COUNT);
+    assert_roundtrip::<[i8; 8]>([0, 1, u128::from(u64::MAX)]);
+}
+
+#[test]
+fn crate_types() {
+    exhaustive_newtype! {
+        struct Digit(u8) where 0..10;
+    }
+
+    assert_same::<RangedLenU8<1, 6>>();
+    assert_same::<RangedLenI8<-2, 5>>();
+    assert_eq!(1_000_001, RangedLenU32::<0, 1_000_001>::COUNT);
+    assert_roundtrip::<RangedLenU32<0, 1_000_001>>([0, 1, 1_000_000]);
+    assert_same::<Digit>();
+    assert_same::<ExhaustiveSet<Direction>>();
+    assert_same::<ReverseOrder<(bool, Option<bool>)>>();
+    assert_same::<GrayOrder<(bool, Option<bool>)>>();
+    assert_same::<ColexOrder<(bool, Option<bool>)>>();
+}
+
+#[test]
+fn large() {
+    type Bits = [bool; 64];
+    type Grid = [[Option<Direction>; 6]; 6];
+    type Set = ExhaustiveSet<RangedLenU8<0, 100>>;
+
+    assert_eq!(1 << 64, Bits::COUNT);
+    assert_eq!(Some([true; 64]), Bits::unrank(Bits::COUNT - 1));
+    assert_roundtrip::<Bits>([0, 1, 12_345_678_987_654_321, Bits::COUNT - 1]);
+
+    assert_eq!(5u128.pow(36), Grid::COUNT);
+    assert_roundtrip::<Grid>([0, 5u128.pow(35), Grid::COUNT / 3, Grid::COUNT - 1]);
+
+    assert_eq!(1 << 100, Set::COUNT);
+    // largest set whose subsets can be counted
+    assert_eq!(1 << 127, ExhaustiveSet::<RangedLenU8<0, 127>>::COUNT);
+    let set = Set::unrank(1).expect("rank should be in bounds");
+    assert_eq!(
+        vec![99],
+        set.iter().map(RangedLenU8::get).collect::<Vec<_>>()
+    );
+    assert_roundtrip::<Set>([0, 1, 1 << 99, Set::COUNT - 1]);
+
+    assert_eq!(
+        Some(ReverseOrder([true; 64])),
+        ReverseOrder::<Bits>::unrank(0)
+    );
+    assert_roundtrip::<ReverseOrder<Bits>>([0, 1, Bits::COUNT - 1]);
+}
+
+// defines each type twice, once in `exhaustive` deriving only `Exhaustive`, and
+// once in `finite` deriving only `Finite`, so that we can check that both
+// derives order values in the same way
+macro_rules! twins {
+    ($($item:item)*) => {
+        mod exhaustive {
+            use super::*;
+
+            $(
+                #[derive(Debug, Clone, Copy, PartialEq, Eq, Exhaustive)]
+                $item
+            )*
+        }
+
+        mod finite {
+            use super::*;
+
+            $(
+                #[derive(Debug, Clone, Copy, PartialEq, Eq, Finite)]
+                $item
+            )*
+        }
+    };
+}
+
+twins! {
+    pub struct Unit;
+
+    pub struct Tuple(pub bool, pub Option<Direction>);
+
+    pub struct Named<T> {
+        pub a: T,
+        pub b: Ordering,
+    }
+
+    pub enum Empty {}
+
+    pub enum Event<T> {
+        Tick,
+        Input(T),
+        Move { from: Direction, to: Direction },
+    }
+
+    pub struct Values {
+        #[exhaustive(unsafe(values = 2..=4))]
+        pub a: u8,
+        #[exhaustive(unsafe(values = [1, 2, 4, 8]))]
+        pub b: u16,
+        #[exhaustive(unsafe(fixed = "tag"))]
+        pub c: &'static str,
+    }
+
+    #[exhaustive(order = "reverse")]
+    pub enum Reversed {
+        A,
+        B(bool, Option<bool>),
+    }
+}
+
+// `Finite` must rank values in the same order as `Exhaustive::ALL` of an
+// identical type
+#[track_caller]
+fn assert_twins<E: Exhaustive + Finite + Debug + PartialEq, F: Finite + Debug + PartialEq>() {
+    assert_same::<E>();
+    assert_eq!(E::Num::USIZE as u128, F::COUNT);
+    for (index, value) in E::iter().enumerate() {
+        let twin = F::unrank(index as u128).expect("rank should be in bounds");
+        assert_eq!(format!("{value:?}"), format!("{twin:?}"));
+        assert_eq!(index as u128, twin.rank());
+    }
+    assert_eq!(None, F::unrank(F::COUNT));
+}
+
+#[test]
+fn derive_struct() {
+    assert_twins::<exhaustive::Unit, finite::Unit>();
+    assert_twins::<exhaustive::Tuple, finite::Tuple>();
+    assert_twins::<exhaustive::Named<bool>, finite::Named<bool>>();
+    assert_twins::<exhaustive::Named<Option<Direction>>, finite::Named<Option<Direction>>>();
+}
+
+#[test]
+fn derive_enum() {
+    assert_twins::<exhaustive::Empty, finite::Empty>();
+    assert_twins::<exhaustive::Event<bool>, finite::Event<bool>>();
+    assert_twins::<exhaustive::Event<Option<bool>>, finite::Event<Option<bool>>>();
+}
+
+#[test]
+fn derive_attributes() {
+    #[derive(Debug, Clone, Copy, PartialEq, Finite)]
+    #[exhaustive(bound = "")]
+    struct Bounded<T> {
+        #[exhaustive(unsafe(fixed = PhantomData))]
+        marker: PhantomData<T>,
+        value: bool,
+    }
+
+    assert_twins::<exhaustive::Values, finite::Values>();
+    assert_twins::<exhaustive::Reversed, finite::Reversed>();
+    assert_eq!(2, Bounded::<fn()>::COUNT);
+}
+
+#[test]
+fn derive_large() {
+    #[derive(Debug, Clone, Copy, PartialEq, Finite)]
+    struct Board {
+        cells: [[Option<Direction>; 6]; 6],
+        turn: bool,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Finite)]
+    enum Game {
+        Menu,
+        Playing(Board),
+        Over { winner: bool },
+    }
+
+    assert_eq!(5u128.pow(36) * 2, Board::COUNT);
+    assert_eq!(1 + Board::COUNT + 2, Game::COUNT);
+    assert_roundtrip::<Game>([0, 1, Board::COUNT / 2, Board::COUNT, Game::COUNT - 1]);
+    assert_eq!(
+        Some(Game::Over { winner: false }),
+        Game::unrank(Board::COUNT + 1)
+    );
+}
@@ -1,10 +1,10 @@
 #![expect(missing_docs, reason = "test module")]
 
 #[test]
-#[expect(
-    dead_code,
-    reason = "if we're getting dead code warnings, we've succeeded"
-)]
+// the derived `index` reads every field, so the only dead code left is that
+// these types are never constructed outside of their derived impls - which
+// rustc does not count towards an `#[expect]` on the enclosing function
+#[allow(dead_code, reason = "types only exist to cause ident conflicts")]
 const fn hygiene() {
     // try and cause as many ident conflicts as possible
 
@@ -4,8 +4,14 @@ use {
     const_exhaustive::Exhaustive,
     core::{
         any::Any,
+        cmp::{Ordering, Reverse},
         convert::Infallible,
+        fmt::Alignment,
         marker::{PhantomData, PhantomPinned},
+        mem::ManuallyDrop,
+        num::{FpCategory, Saturating, Wrapping},
+        ops::{Bound, ControlFlow},
+        task::Poll,
     },
 };
 
@@ -13,6 +19,12 @@ use {
 fn assert_all<T: Exhaustive + core::fmt::Debug + PartialEq>(values: impl IntoIterator<Item = T>) {
     let values = values.into_iter().collect::<Vec<_>>();
     assert_eq!(values.as_slice(), T::ALL.as_slice());
+
+    for (index, value) in values.iter().enumerate() {
+        assert_eq!(index, value.index());
+        assert_eq!(Some(*value), T::from_index(index));
+    }
+    assert_eq!(None, T::from_index(values.len()));
 }
 
 #[test]
@@ -68,6 +80,68 @@ fn results() {
     assert_all([Ok(false), Ok(true), Err(false), Err(true)]);
 }
 
+#[test]
+fn orderings() {
+    assert_all([Ordering::Less, Ordering::Equal, Ordering::Greater]);
+}
+
+#[test]
+fn alignments() {
+    assert_all([Alignment::Left, Alignment::Right, Alignment::Center]);
+}
+
+#[test]
+fn fp_categories() {
+    assert_all([
+        FpCategory::Nan,
+        FpCategory::Infinite,
+        FpCategory::Zero,
+        FpCategory::Subnormal,
+        FpCategory::Normal,
+    ]);
+}
+
+#[test]
+fn bounds() {
+    assert_all([Bound::<Infallible>::Unbounded]);
+    assert_all([Bound::Included(()), Bound::Excluded(()), Bound::Unbounded]);
+    assert_all([
+        Bound::Included(false),
+        Bound::Included(true),
+        Bound::Excluded(false),
+        Bound::Excluded(true),
+        Bound::Unbounded,
+    ]);
+}
+
+#[test]
+fn control_flows() {
+    assert_all::<ControlFlow<Infallible, Infallible>>([]);
+    assert_all([ControlFlow::<Infallible, _>::Continue(())]);
+    assert_all([ControlFlow::<_, Infallible>::Break(())]);
+    assert_all([
+        ControlFlow::Continue(false),
+        ControlFlow::Continue(true),
+        ControlFlow::Break(()),
+    ]);
+}
+
+#[test]
+fn polls() {
+    assert_all([Poll::<Infallible>::Pending]);
+    assert_all([Poll::Ready(()), Poll::Pending]);
+    assert_all([Poll::Ready(false), Poll::Ready(true), Poll::Pending]);
+}
+
+#[test]
+fn wrappers() {
+    assert_all::<Reverse<Infallible>>([]);
+    assert_all([Reverse(None), Reverse(Some(false)), Reverse(Some(true))]);
+    assert_all([Wrapping(false), Wrapping(true)]);
+    assert_all([Saturating(false), Saturating(true)]);
+    assert_all([ManuallyDrop::new(false), ManuallyDrop::new(true)]);
+}
+
 #[test]
 fn arrays() {
     assert_all::<[Infallible; 0]>([[]]);
@@ -142,3 +216,35 @@ fn tuples() {
         (true, Some(true)),
     ]);
 }
+
+#[test]
+#[cfg(feature = "small-ints")]
+fn small_ints() {
+    assert_all(0..=u8::MAX);
+    assert_all(i8::MIN..=i8::MAX);
+    assert_eq!(0x80, 0i8.index());
+    assert_eq!(Some(-1i8), i8::from_index(0x7f));
+}
+
+#[test]
+#[cfg(feature = "small-ints")]
+fn small_non_zero_ints() {
+    use core::num::NonZero;
+
+    assert_all((1..=u8::MAX).map(|n| NonZero::new(n).expect("should not be zero")));
+    assert_all(
+        (i8::MIN..=i8::MAX)
+            .filter(|n| *n != 0)
+            .map(|n| NonZero::new(n).expect("should not be zero")),
+    );
+    assert_eq!(NonZero::<i8>::new(1), NonZero::<i8>::from_index(0x80),);
+}
+
+#[test]
+#[cfg(feature = "medium-ints")]
+fn medium_ints() {
+    assert_all(0..=u16::MAX);
+    assert_all(i16::MIN..=i16::MAX);
+    assert_eq!(0x8000, 0i16.index());
+    assert_eq!(Some(-1i16), i16::from_index(0x7fff));
+}
@@ -0,0 +1,73 @@
+#![expect(missing_docs, reason = "test module")]
+
+use {
+    const_exhaustive::Exhaustive,
+    core::{convert::Infallible, fmt::Debug},
+};
+
+#[track_caller]
+fn assert_iter<T: Exhaustive + Debug + PartialEq>() {
+    let all = T::ALL;
+    let all = all.as_slice();
+
+    assert_eq!(all, T::iter().collect::<Vec<_>>());
+    assert_eq!(
+        all.iter().rev().copied().collect::<Vec<_>>(),
+        T::iter().rev().collect::<Vec<_>>()
+    );
+    assert_eq!(all.len(), T::iter().len());
+    assert_eq!(all.len(), T::iter().count());
+    assert_eq!(all.last().copied(), T::iter().last());
+
+    for n in 0..=all.len() {
+        assert_eq!(all.get(n).copied(), T::iter().nth(n));
+        assert_eq!(all.iter().nth_back(n).copied(), T::iter().nth_back(n));
+        assert_eq!(all.len().saturating_sub(n), T::iter().skip(n).len());
+    }
+}
+
+#[test]
+fn primitives() {
+    assert_iter::<Infallible>();
+    assert_iter::<()>();
+    assert_iter::<bool>();
+}
+
+#[test]
+fn compound() {
+    assert_iter::<Option<bool>>();
+    assert_iter::<Result<bool, Option<bool>>>();
+    assert_iter::<(bool, Option<bool>, ())>();
+    assert_iter::<[Option<bool>; 3]>();
+}
+
+#[test]
+fn derived() {
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    enum Dummy {
+        A,
+        B(bool, Option<bool>),
+        C { a: [bool; 2] },
+    }
+
+    assert_iter::<Dummy>();
+}
+
+#[test]
+fn fused() {
+    let mut iter = bool::iter();
+    assert_eq!(Some(false), iter.next());
+    assert_eq!(Some(true), iter.next());
+    assert_eq!(None, iter.next());
+    assert_eq!(None, iter.next());
+    assert_eq!(None, iter.next_back());
+}
+
+#[test]
+fn large() {
+    // don't materialize `ALL` here
+    let mut iter = <[Option<bool>; 8]>::iter();
+    assert_eq!(6561, iter.len());
+    assert_eq!(Some([Some(true); 8]), iter.nth(6560));
+    assert_eq!(None, iter.next());
+}
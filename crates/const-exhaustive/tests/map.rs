@@ -0,0 +1,85 @@
+#![expect(missing_docs, reason = "test module")]
+
+use const_exhaustive::{Exhaustive, ExhaustiveMap, exhaustive_map};
+
+#[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+const OPPOSITE: ExhaustiveMap<Direction, Direction> = exhaustive_map!(Direction, |dir| {
+    match dir {
+        Direction::North => Direction::South,
+        Direction::South => Direction::North,
+        Direction::East => Direction::West,
+        Direction::West => Direction::East,
+    }
+});
+
+#[test]
+fn const_construct() {
+    assert_eq!(Direction::South, OPPOSITE[Direction::North]);
+    assert_eq!(Direction::North, OPPOSITE[Direction::South]);
+    assert_eq!(Direction::West, OPPOSITE[Direction::East]);
+    assert_eq!(Direction::East, OPPOSITE[Direction::West]);
+}
+
+#[test]
+fn from_fn() {
+    let map = ExhaustiveMap::<Option<bool>, _>::from_fn(|value| value.is_some());
+    assert_eq!(
+        [(None, &false), (Some(false), &true), (Some(true), &true)],
+        map.iter().collect::<Vec<_>>().as_slice(),
+    );
+    assert_eq!(map, exhaustive_map!(Option<bool>, |value| value.is_some()));
+}
+
+#[test]
+fn get_mut() {
+    let mut map = ExhaustiveMap::<(bool, bool), u32>::default();
+    map[(true, false)] += 1;
+    *map.get_mut((false, true)) += 2;
+    for value in map.values_mut() {
+        *value += 1;
+    }
+
+    assert_eq!(&1, map.get((false, false)));
+    assert_eq!(&3, map.get((false, true)));
+    assert_eq!(&2, map.get((true, false)));
+    assert_eq!(&1, map.get((true, true)));
+}
+
+#[test]
+fn iter() {
+    let map = ExhaustiveMap::<bool, _>::from_fn(u8::from);
+    assert_eq!([false, true], map.keys().collect::<Vec<_>>().as_slice());
+    assert_eq!([&0, &1], map.values().collect::<Vec<_>>().as_slice());
+    assert_eq!(
+        [(true, &1), (false, &0)],
+        map.iter().rev().collect::<Vec<_>>().as_slice(),
+    );
+    assert_eq!(
+        [(false, 0), (true, 1)],
+        map.into_iter().collect::<Vec<_>>().as_slice(),
+    );
+}
+
+#[test]
+fn map_zip() {
+    let a = ExhaustiveMap::<bool, _>::from_fn(u8::from);
+    let b = a.map(|key, value| (!key, value * 2));
+    assert_eq!(&(true, 0), b.get(false));
+    assert_eq!(&(false, 2), b.get(true));
+
+    let zipped = a.zip(b);
+    assert_eq!(&(1, (false, 2)), zipped.get(true));
+}
+
+#[test]
+fn debug() {
+    let map = ExhaustiveMap::<bool, _>::from_fn(u8::from);
+    assert_eq!("{false: 0, true: 1}", format!("{map:?}"));
+}
@@ -0,0 +1,119 @@
+#![expect(missing_docs, reason = "test module")]
+
+use const_exhaustive::{ConstEq, Exhaustive, debug_verify, exhaustive_newtype, verify_exhaustive};
+
+exhaustive_newtype! {
+    /// Single decimal digit.
+    pub struct Digit(u8) where 0..10;
+}
+
+exhaustive_newtype! {
+    struct Offset(i8) where -128..=127;
+}
+
+exhaustive_newtype! {
+    struct Huge(u32) where 3_000_000_000..3_000_000_005;
+}
+
+exhaustive_newtype! {
+    struct Wide(u64) where 10_000_000_000..=10_000_000_002;
+}
+
+exhaustive_newtype! {
+    pub(crate) struct Lowercase(char) where 'a'..='z';
+}
+
+exhaustive_newtype! {
+    struct Octal(char) where '0'..'8';
+}
+
+exhaustive_newtype! {
+    struct Flag(u16) in [1, 2, 4, 8, 1024];
+}
+
+verify_exhaustive!(Digit);
+verify_exhaustive!(Offset);
+verify_exhaustive!(Huge);
+verify_exhaustive!(Wide);
+verify_exhaustive!(Lowercase);
+verify_exhaustive!(Octal);
+verify_exhaustive!(Flag);
+
+#[test]
+fn int_range() {
+    debug_verify::<Digit>();
+    assert_eq!(10, Digit::ALL.len());
+    assert_eq!(
+        (0..10).collect::<Vec<_>>(),
+        Digit::iter().map(Digit::get).collect::<Vec<_>>()
+    );
+    assert_eq!(Some(3), Digit::new(3).map(Digit::get));
+    assert_eq!(None, Digit::new(10));
+    assert_eq!("Digit(3)", format!("{:?}", Digit::ALL[3]));
+}
+
+#[test]
+fn int_range_inclusive() {
+    debug_verify::<Offset>();
+    assert_eq!(256, Offset::ALL.len());
+    assert_eq!(Some(-128), Offset::from_index(0).map(Offset::get));
+    assert_eq!(Some(127), Offset::from_index(255).map(Offset::get));
+    assert_eq!(Some(128), Offset::new(0).map(Offset::index));
+}
+
+#[test]
+fn large_bounds() {
+    debug_verify::<Huge>();
+    debug_verify::<Wide>();
+    assert_eq!(5, Huge::ALL.len());
+    assert_eq!(Some(3_000_000_004), Huge::from_index(4).map(Huge::get));
+    assert_eq!(None, Huge::new(3_000_000_005));
+    assert_eq!(
+        [10_000_000_000, 10_000_000_001, 10_000_000_002],
+        Wide::iter().map(Wide::get).collect::<Vec<_>>().as_slice()
+    );
+    assert_eq!(Some(1), Wide::new(10_000_000_001).map(Wide::index));
+}
+
+#[test]
+fn char_range() {
+    debug_verify::<Lowercase>();
+    debug_verify::<Octal>();
+    assert_eq!(26, Lowercase::ALL.len());
+    assert_eq!(Some(25), Lowercase::new('z').map(Lowercase::index));
+    assert_eq!(None, Lowercase::new('A'));
+    assert_eq!(
+        "01234567",
+        Octal::iter().map(Octal::get).collect::<String>()
+    );
+    assert_eq!(None, Octal::new('8'));
+}
+
+#[test]
+fn list() {
+    debug_verify::<Flag>();
+    assert_eq!(
+        [1, 2, 4, 8, 1024],
+        Flag::iter().map(Flag::get).collect::<Vec<_>>().as_slice()
+    );
+    assert_eq!(Some(4), Flag::new(1024).map(Flag::index));
+    assert_eq!(None, Flag::new(3));
+    assert_eq!(None, Flag::from_index(5));
+}
+
+#[test]
+fn ordering() {
+    assert!(Digit::new(2) < Digit::new(7));
+    assert!(Offset::new(-1) < Offset::new(1));
+}
+
+#[test]
+fn in_derive() {
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive, ConstEq)]
+    struct Code(Digit, Option<Lowercase>);
+
+    debug_verify::<Code>();
+    assert_eq!(10 * 27, Code::ALL.len());
+    assert!(Code::ALL[0].const_eq(&Code::ALL[0]));
+    assert!(!Code::ALL[0].const_eq(&Code::ALL[1]));
+}
@@ -0,0 +1,185 @@
+#![expect(missing_docs, reason = "test module")]
+
+use const_exhaustive::{
+    ColexOrder, Exhaustive, ExhaustiveProduct, GrayOrder, ReverseOrder, debug_verify,
+};
+
+// number of fields which differ between `a` and `b`
+fn diff<const N: usize>(a: [usize; N], b: [usize; N]) -> usize {
+    a.iter().zip(b).filter(|(a, b)| **a != *b).count()
+}
+
+fn assert_gray<T: Exhaustive, const N: usize>(fields: impl Fn(T) -> [usize; N]) {
+    let all = T::ALL;
+    for pair in all.as_slice().windows(2) {
+        assert_eq!(1, diff(fields(pair[0]), fields(pair[1])));
+    }
+}
+
+#[test]
+fn radices() {
+    assert_eq!(<[usize; 0]>::default(), <()>::RADICES);
+    assert_eq!([2, 3, 1], <(bool, Option<bool>, ())>::RADICES);
+    assert_eq!([3, 3], <[Option<bool>; 2]>::RADICES);
+}
+
+#[test]
+fn derive_radices() {
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    struct Unit;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    struct Fields<T> {
+        a: Option<bool>,
+        #[exhaustive(unsafe(values = 1..=4))]
+        b: u8,
+        #[exhaustive(unsafe(fixed = "tag"))]
+        c: &'static str,
+        d: T,
+    }
+
+    assert_eq!(<[usize; 0]>::default(), Unit::RADICES);
+    assert_eq!([3, 4, 1, 2], Fields::<bool>::RADICES);
+    assert_eq!([3, 4, 1, 3], Fields::<Option<bool>>::RADICES);
+    debug_verify::<GrayOrder<Fields<bool>>>();
+    assert_gray(
+        |GrayOrder(Fields { a, b, d, .. }): GrayOrder<Fields<bool>>| {
+            [a.index(), usize::from(b), 0, d.index()]
+        },
+    );
+}
+
+#[test]
+fn reverse() {
+    debug_verify::<ReverseOrder<Option<bool>>>();
+    debug_verify::<ReverseOrder<(bool, Option<bool>)>>();
+    assert_eq!(
+        <(bool, bool)>::iter().rev().collect::<Vec<_>>(),
+        ReverseOrder::<(bool, bool)>::iter()
+            .map(|ReverseOrder(t)| t)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn gray() {
+    debug_verify::<GrayOrder<(bool, bool, bool)>>();
+    debug_verify::<GrayOrder<(Option<bool>, bool, Option<bool>)>>();
+    debug_verify::<GrayOrder<[Option<bool>; 3]>>();
+    assert_eq!(
+        [
+            GrayOrder([false, false, false]),
+            GrayOrder([false, false, true]),
+            GrayOrder([false, true, true]),
+            GrayOrder([false, true, false]),
+            GrayOrder([true, true, false]),
+            GrayOrder([true, true, true]),
+            GrayOrder([true, false, true]),
+            GrayOrder([true, false, false]),
+        ],
+        GrayOrder::<[bool; 3]>::ALL.as_slice()
+    );
+
+    assert_gray(
+        |GrayOrder((a, b, c)): GrayOrder<(Option<bool>, bool, Option<bool>)>| {
+            [a.index(), b.index(), c.index()]
+        },
+    );
+    assert_gray(|GrayOrder(t): GrayOrder<[Option<bool>; 3]>| t.map(Exhaustive::index));
+}
+
+#[test]
+fn colex() {
+    debug_verify::<ColexOrder<(bool, Option<bool>)>>();
+    debug_verify::<ColexOrder<[Option<bool>; 3]>>();
+    assert_eq!(
+        [
+            ColexOrder((None, false)),
+            ColexOrder((Some(false), false)),
+            ColexOrder((Some(true), false)),
+            ColexOrder((None, true)),
+            ColexOrder((Some(false), true)),
+            ColexOrder((Some(true), true)),
+        ],
+        ColexOrder::<(Option<bool>, bool)>::ALL.as_slice()
+    );
+}
+
+#[test]
+#[expect(clippy::items_after_statements, reason = "easier to read")]
+fn derive() {
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    #[exhaustive(order = "gray")]
+    struct Gray(Option<bool>, bool, Option<bool>);
+    debug_verify::<Gray>();
+    assert_eq!(
+        GrayOrder::<(Option<bool>, bool, Option<bool>)>::iter()
+            .map(|GrayOrder((a, b, c))| Gray(a, b, c))
+            .collect::<Vec<_>>(),
+        Gray::ALL.as_slice()
+    );
+
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    #[exhaustive(order = "colex")]
+    struct Colex {
+        a: Option<bool>,
+        b: bool,
+    }
+    debug_verify::<Colex>();
+    assert_eq!(
+        ColexOrder::<(Option<bool>, bool)>::iter()
+            .map(|ColexOrder((a, b))| Colex { a, b })
+            .collect::<Vec<_>>(),
+        Colex::ALL.as_slice()
+    );
+
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    #[exhaustive(order = "reverse")]
+    enum Reverse {
+        A,
+        B(bool, Option<bool>),
+        C,
+    }
+    debug_verify::<Reverse>();
+    assert_eq!(Reverse::C, Reverse::ALL[0]);
+    assert_eq!(Reverse::B(true, Some(true)), Reverse::ALL[1]);
+    assert_eq!(Reverse::A, Reverse::ALL[7]);
+
+    // variants stay in order, but fields of each variant are reordered
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    #[exhaustive(order = "gray")]
+    enum GrayEnum<T> {
+        A,
+        B(bool, bool),
+        C { a: T, b: Option<bool> },
+    }
+    debug_verify::<GrayEnum<bool>>();
+    assert_eq!(
+        [
+            GrayEnum::A,
+            GrayEnum::B(false, false),
+            GrayEnum::B(false, true),
+            GrayEnum::B(true, true),
+            GrayEnum::B(true, false),
+            GrayEnum::C { a: false, b: None },
+            GrayEnum::C {
+                a: false,
+                b: Some(false)
+            },
+            GrayEnum::C {
+                a: false,
+                b: Some(true)
+            },
+            GrayEnum::C {
+                a: true,
+                b: Some(true)
+            },
+        ],
+        GrayEnum::<bool>::ALL.as_slice()[..9]
+    );
+
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    #[exhaustive(order = "reverse")]
+    enum Empty {}
+    debug_verify::<Empty>();
+}
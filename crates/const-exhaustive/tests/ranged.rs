@@ -0,0 +1,98 @@
+#![expect(missing_docs, reason = "test module")]
+
+use const_exhaustive::{Exhaustive, RangedLenI8, RangedLenI64, RangedLenU8, RangedLenU64};
+
+#[test]
+#[expect(clippy::items_after_statements, reason = "easier to read")]
+fn bounds() {
+    type R = RangedLenU8<2, 4>;
+    assert_eq!(2, R::MIN);
+    assert_eq!(5, R::MAX);
+    assert_eq!(None, R::new(1));
+    assert_eq!(Some(2), R::new(2).map(R::get));
+    assert_eq!(Some(5), R::new(5).map(R::get));
+    assert_eq!(None, R::new(6));
+
+    type Full = RangedLenU8<0, 256>;
+    assert_eq!(0, Full::MIN);
+    assert_eq!(255, Full::MAX);
+    assert_eq!(Some(255), Full::new(255).map(Full::get));
+
+    type Signed = RangedLenI8<-128, 3>;
+    assert_eq!(-128, Signed::MIN);
+    assert_eq!(-126, Signed::MAX);
+    assert_eq!(None, Signed::new(-125));
+
+    type Large = RangedLenU64<{ u64::MAX - 1 }, 2>;
+    assert_eq!(u64::MAX, Large::MAX);
+    assert_eq!(Some(u64::MAX), Large::new(u64::MAX).map(Large::get));
+}
+
+#[test]
+#[expect(clippy::items_after_statements, reason = "easier to read")]
+fn arithmetic() {
+    type R = RangedLenI8<-2, 5>;
+    let zero = R::new(0).expect("should be in range");
+
+    assert_eq!(Some(2), zero.checked_add(2).map(R::get));
+    assert_eq!(None, zero.checked_add(3));
+    assert_eq!(Some(-2), zero.checked_sub(2).map(R::get));
+    assert_eq!(None, zero.checked_sub(3));
+    assert_eq!(None, zero.checked_add(i8::MAX));
+
+    assert_eq!(2, zero.saturating_add(100).get());
+    assert_eq!(-2, zero.saturating_sub(100).get());
+    assert_eq!(-2, zero.saturating_add(i8::MIN).get());
+
+    assert_eq!(-2, zero.wrapping_add(3).get());
+    assert_eq!(2, zero.wrapping_sub(3).get());
+    assert_eq!(0, zero.wrapping_add(10).get());
+    assert_eq!(-1, zero.wrapping_add(-6).get());
+
+    assert_eq!(2, R::new_saturating(i8::MAX).get());
+    assert_eq!(-2, R::new_saturating(i8::MIN).get());
+    assert_eq!(1, R::new_wrapping(6).get());
+
+    type Large = RangedLenI64<{ i64::MIN }, 4>;
+    let min = Large::new(i64::MIN).expect("should be in range");
+    assert_eq!(i64::MIN + 3, min.saturating_add(i64::MAX).get());
+    assert_eq!(i64::MIN + 1, min.wrapping_sub(3).get());
+}
+
+#[test]
+fn exhaustive() {
+    type R = RangedLenI8<-1, 3>;
+    assert_eq!(
+        [-1, 0, 1],
+        R::ALL
+            .into_iter()
+            .map(R::get)
+            .collect::<Vec<_>>()
+            .as_slice()
+    );
+    for (index, value) in R::ALL.into_iter().enumerate() {
+        assert_eq!(index, value.index());
+        assert_eq!(Some(value), R::from_index(index));
+    }
+    assert_eq!(None, R::from_index(3));
+
+    assert_eq!(256, RangedLenU8::<0, 256>::ALL.len());
+}
+
+#[test]
+fn derive() {
+    #[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+    struct Roll {
+        dice: RangedLenU8<1, 6>,
+        lucky: bool,
+    }
+
+    assert_eq!(12, Roll::ALL.len());
+    assert_eq!(
+        Some(Roll {
+            dice: RangedLenU8::new(2).expect("should be in range"),
+            lucky: true,
+        }),
+        Roll::from_index(3),
+    );
+}
@@ -0,0 +1,112 @@
+#![expect(missing_docs, reason = "test module")]
+#![expect(dead_code, reason = "remote definitions are never constructed")]
+
+use const_exhaustive::{Exhaustive, ExhaustiveSet, ExhaustiveVia, Via, debug_verify};
+
+// pretend that this is another crate
+mod other {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Mode {
+        Off,
+        On { level: Level, boost: bool },
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Level(pub bool, pub bool);
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Pair<T> {
+        pub a: T,
+        pub b: T,
+    }
+}
+
+use other::{Level, Mode, Pair};
+
+#[derive(Exhaustive)]
+#[exhaustive(remote = other::Level)]
+struct LevelDef(bool, bool);
+
+#[derive(Exhaustive)]
+#[exhaustive(remote = "other::Mode")]
+enum ModeDef {
+    Off,
+    On {
+        #[exhaustive(with = LevelDef)]
+        level: Level,
+        boost: bool,
+    },
+}
+
+#[derive(Exhaustive)]
+#[exhaustive(remote = other::Pair)]
+struct PairDef<T> {
+    a: T,
+    b: T,
+}
+
+#[test]
+fn remote_struct() {
+    assert_eq!(
+        [
+            Level(false, false),
+            Level(false, true),
+            Level(true, false),
+            Level(true, true),
+        ],
+        LevelDef::ALL.as_slice()
+    );
+    assert_eq!(3, LevelDef::index(Level(true, true)));
+    assert_eq!(Some(Level(false, true)), LevelDef::from_index(1));
+    assert_eq!(None, LevelDef::from_index(4));
+}
+
+#[test]
+fn remote_enum() {
+    assert_eq!(
+        [
+            Mode::Off,
+            Mode::On {
+                level: Level(false, false),
+                boost: false
+            },
+            Mode::On {
+                level: Level(false, false),
+                boost: true
+            },
+        ],
+        ModeDef::ALL.as_slice()[..3]
+    );
+    assert_eq!(9, ModeDef::ALL.len());
+    debug_verify::<Via<Mode, ModeDef>>();
+}
+
+#[test]
+fn remote_generic() {
+    assert_eq!(
+        [
+            Pair { a: false, b: false },
+            Pair { a: false, b: true },
+            Pair { a: true, b: false },
+            Pair { a: true, b: true },
+        ],
+        <PairDef<bool> as ExhaustiveVia<Pair<bool>>>::ALL.as_slice()
+    );
+    debug_verify::<Via<Pair<Option<bool>>, PairDef<Option<bool>>>>();
+}
+
+#[test]
+fn via() {
+    type ModeVia = Via<Mode, ModeDef>;
+
+    let mut set = ExhaustiveSet::<ModeVia>::new();
+    set.insert(ModeVia::new(Mode::Off));
+    assert!(set.contains(ModeVia::new(Mode::Off)));
+    assert_eq!(1, set.len());
+
+    debug_verify::<(ModeVia, bool)>();
+    assert_eq!(
+        Some(Mode::Off),
+        <(ModeVia, bool)>::from_index(1).map(|(mode, _)| mode.get())
+    );
+}
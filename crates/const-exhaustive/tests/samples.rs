@@ -0,0 +1,229 @@
+#![expect(missing_docs, reason = "test module")]
+
+use {
+    const_exhaustive::{Exhaustive, ExhaustiveVia, Samples, generic_array::GenericArray, typenum},
+    core::{
+        num::{NonZero, Wrapping},
+        ops::Bound,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+enum Unit {
+    Meters,
+    Feet,
+}
+
+#[test]
+fn exhaustive() {
+    assert_eq!(bool::ALL, bool::SAMPLES);
+    assert_eq!(Option::<Unit>::ALL, Option::<Unit>::SAMPLES);
+    assert_eq!(<(bool, Unit)>::ALL, <(bool, Unit)>::SAMPLES);
+}
+
+#[test]
+fn primitives() {
+    assert_eq!([0, 1, u32::MAX], u32::SAMPLES.as_slice());
+    assert_eq!([0, 1, usize::MAX], usize::SAMPLES.as_slice());
+    assert_eq!([i64::MIN, -1, 0, 1, i64::MAX], i64::SAMPLES.as_slice());
+    assert_eq!(['\0', 'a', 'é', char::MAX], char::SAMPLES.as_slice());
+    assert_eq!(4, <&str>::SAMPLES.len());
+
+    let floats = f32::SAMPLES;
+    assert_eq!(10, floats.len());
+    assert!(floats[0].is_infinite() && floats[0].is_sign_negative());
+    assert!(floats[3] == 0.0 && floats[3].is_sign_negative());
+    assert!(floats[4] == 0.0 && floats[4].is_sign_positive());
+    assert!(floats[9].is_nan());
+    assert!(f64::SAMPLES[9].is_nan());
+}
+
+// the same whether or not `small-ints` and `medium-ints` are enabled
+#[test]
+fn small_ints() {
+    assert_eq!([0, 1, u8::MAX], u8::SAMPLES.as_slice());
+    assert_eq!([i8::MIN, -1, 0, 1, i8::MAX], i8::SAMPLES.as_slice());
+    assert_eq!([0, 1, u16::MAX], u16::SAMPLES.as_slice());
+    assert_eq!(
+        [NonZero::<u8>::MIN, NonZero::<u8>::MAX],
+        NonZero::<u8>::SAMPLES.as_slice()
+    );
+    assert_eq!(
+        [-128, -1, 1, 127],
+        NonZero::<i8>::SAMPLES
+            .into_iter()
+            .map(NonZero::get)
+            .collect::<Vec<_>>()
+            .as_slice()
+    );
+}
+
+#[test]
+fn wrappers() {
+    assert_eq!(
+        [None, Some(0), Some(1), Some(u32::MAX)],
+        Option::<u32>::SAMPLES.as_slice()
+    );
+    assert_eq!(
+        [Ok(0), Ok(1), Ok(u8::MAX), Err(false), Err(true)],
+        Result::<u8, bool>::SAMPLES.as_slice()
+    );
+    assert_eq!(
+        [Wrapping(0), Wrapping(1), Wrapping(u64::MAX)],
+        Wrapping::<u64>::SAMPLES.as_slice()
+    );
+    assert_eq!(
+        [
+            (0, false),
+            (0, true),
+            (1, false),
+            (1, true),
+            (u32::MAX, false),
+            (u32::MAX, true),
+        ],
+        <(u32, bool)>::SAMPLES.as_slice()
+    );
+    assert_eq!(
+        [[0, 0], [0, 1], [0, u16::MAX], [1, 0]],
+        <[u16; 2]>::SAMPLES[..4]
+    );
+    assert_eq!(9, <[u16; 2]>::SAMPLES.len());
+    assert_eq!(2 * 10 + 1, Bound::<f32>::SAMPLES.len());
+}
+
+#[test]
+fn derive_struct() {
+    #[derive(Debug, Clone, Copy, PartialEq, Samples)]
+    struct Length {
+        value: u32,
+        unit: Unit,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Samples)]
+    struct Pair<T>(T, bool);
+
+    assert_eq!(
+        [
+            Length {
+                value: 0,
+                unit: Unit::Meters
+            },
+            Length {
+                value: 0,
+                unit: Unit::Feet
+            },
+            Length {
+                value: 1,
+                unit: Unit::Meters
+            },
+            Length {
+                value: 1,
+                unit: Unit::Feet
+            },
+            Length {
+                value: u32::MAX,
+                unit: Unit::Meters
+            },
+            Length {
+                value: u32::MAX,
+                unit: Unit::Feet
+            },
+        ],
+        Length::SAMPLES.as_slice()
+    );
+    assert_eq!(
+        [
+            Pair(i32::MIN, false),
+            Pair(i32::MIN, true),
+            Pair(-1, false),
+            Pair(-1, true),
+            Pair(0, false),
+            Pair(0, true),
+            Pair(1, false),
+            Pair(1, true),
+            Pair(i32::MAX, false),
+            Pair(i32::MAX, true),
+        ],
+        Pair::<i32>::SAMPLES.as_slice()
+    );
+    assert_eq!(4, Pair::<Unit>::SAMPLES.len());
+}
+
+#[test]
+fn derive_enum() {
+    #[derive(Debug, Clone, Copy, PartialEq, Samples)]
+    enum Empty {}
+
+    #[derive(Debug, Clone, Copy, PartialEq, Samples)]
+    enum Shape<T> {
+        Point,
+        Circle { radius: T },
+        Rect(u8, Unit),
+    }
+
+    assert_eq!(0, Empty::SAMPLES.len());
+    assert_eq!(
+        [
+            Shape::Point,
+            Shape::Circle { radius: 0 },
+            Shape::Circle { radius: 1 },
+            Shape::Circle { radius: u64::MAX },
+        ],
+        Shape::<u64>::SAMPLES[..4]
+    );
+    assert_eq!(1 + 3 + u8::SAMPLES.len() * 2, Shape::<u64>::SAMPLES.len());
+    assert_eq!(1 + 2 + u8::SAMPLES.len() * 2, Shape::<bool>::SAMPLES.len());
+}
+
+#[test]
+fn derive_attributes() {
+    // feet first
+    struct UnitDef;
+
+    unsafe impl ExhaustiveVia<Unit> for UnitDef {
+        type Num = typenum::U2;
+
+        const ALL: GenericArray<Unit, Self::Num> =
+            GenericArray::from_array([Unit::Feet, Unit::Meters]);
+
+        fn index(value: Unit) -> usize {
+            match value {
+                Unit::Feet => 0,
+                Unit::Meters => 1,
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Samples)]
+    #[exhaustive(order = "reverse")]
+    struct Attributes {
+        #[exhaustive(unsafe(values = [0.5, 2.0]))]
+        scale: f64,
+        #[exhaustive(unsafe(fixed = "tag"))]
+        tag: &'static str,
+        #[exhaustive(with = UnitDef)]
+        unit: Unit,
+        value: i8,
+    }
+
+    let samples = Attributes::SAMPLES;
+    assert_eq!(2 * 2 * i8::SAMPLES.len(), samples.len());
+    assert_eq!(
+        Attributes {
+            scale: 2.0,
+            tag: "tag",
+            unit: Unit::Meters,
+            value: i8::MAX,
+        },
+        samples[0]
+    );
+    assert_eq!(
+        Attributes {
+            scale: 0.5,
+            tag: "tag",
+            unit: Unit::Feet,
+            value: i8::MIN,
+        },
+        samples[samples.len() - 1]
+    );
+}
@@ -0,0 +1,171 @@
+#![expect(missing_docs, reason = "test module")]
+
+use {
+    const_exhaustive::{Exhaustive, ExhaustiveSet, Subsets},
+    core::convert::Infallible,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Exhaustive)]
+enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+#[test]
+fn empty() {
+    let set = ExhaustiveSet::<Infallible>::new();
+    assert!(set.is_empty());
+    assert_eq!(0, set.len());
+    assert_eq!(set, set.complement());
+    assert_eq!(0, set.iter().count());
+}
+
+#[test]
+fn insert_remove() {
+    let mut set = ExhaustiveSet::new();
+    assert!(set.insert(Direction::East));
+    assert!(!set.insert(Direction::East));
+    assert!(set.insert(Direction::North));
+    assert!(set.contains(Direction::East));
+    assert!(!set.contains(Direction::South));
+    assert_eq!(2, set.len());
+
+    assert!(set.remove(Direction::East));
+    assert!(!set.remove(Direction::East));
+    assert!(!set.contains(Direction::East));
+    assert_eq!(1, set.len());
+
+    set.clear();
+    assert!(set.is_empty());
+}
+
+#[test]
+fn const_insert_remove() {
+    const SET: ExhaustiveSet<Direction> = {
+        let mut set = ExhaustiveSet::new();
+        assert!(set.insert_index(2));
+        assert!(!set.insert_index(2));
+        assert!(set.insert_index(0));
+        assert!(set.remove_index(0));
+        assert!(!set.remove_index(0));
+        set
+    };
+    const CONTAINS: [bool; 2] = [SET.contains_index(0), SET.contains_index(2)];
+
+    assert_eq!([false, true], CONTAINS);
+    assert_eq!([Direction::East], SET.iter().collect::<Vec<_>>().as_slice());
+}
+
+#[test]
+#[should_panic = "index out of bounds"]
+fn index_out_of_bounds() {
+    // still in the first byte, but past the last value
+    let _ = ExhaustiveSet::<Direction>::new().insert_index(4);
+}
+
+#[test]
+fn set_operations() {
+    let a = [Direction::North, Direction::South]
+        .into_iter()
+        .collect::<ExhaustiveSet<_>>();
+    let b = [Direction::South, Direction::East]
+        .into_iter()
+        .collect::<ExhaustiveSet<_>>();
+
+    assert_eq!(
+        [Direction::North, Direction::South, Direction::East],
+        a.union(b).iter().collect::<Vec<_>>().as_slice(),
+    );
+    assert_eq!(
+        [Direction::South],
+        a.intersection(b).iter().collect::<Vec<_>>().as_slice(),
+    );
+    assert_eq!(
+        [Direction::North],
+        a.difference(b).iter().collect::<Vec<_>>().as_slice(),
+    );
+    assert_eq!(
+        [Direction::North, Direction::East],
+        a.symmetric_difference(b)
+            .iter()
+            .collect::<Vec<_>>()
+            .as_slice(),
+    );
+    assert_eq!(
+        [Direction::East, Direction::West],
+        a.complement().iter().collect::<Vec<_>>().as_slice(),
+    );
+
+    assert_eq!(a | b, a.union(b));
+    assert_eq!(a & b, a.intersection(b));
+    assert_eq!(a - b, a.difference(b));
+    assert_eq!(!a, a.complement());
+
+    assert!(a.is_subset(&a.union(b)));
+    assert!(a.union(b).is_superset(&b));
+    assert!(a.is_disjoint(&a.complement()));
+    assert!(!a.is_disjoint(&b));
+}
+
+#[test]
+fn full() {
+    // more than one byte, with a partially filled last byte
+    type Large = Option<(bool, Direction, bool)>;
+
+    let full = ExhaustiveSet::<Large>::full();
+    assert_eq!(17, full.len());
+    assert_eq!(Large::ALL.as_slice(), full.iter().collect::<Vec<_>>());
+    assert!(full.complement().is_empty());
+    assert_eq!(full, ExhaustiveSet::new().complement());
+}
+
+#[test]
+fn iter_rev() {
+    let set = [Some(true), None].into_iter().collect::<ExhaustiveSet<_>>();
+    assert_eq!(
+        [Some(true), None],
+        set.iter().rev().collect::<Vec<_>>().as_slice()
+    );
+}
+
+#[test]
+fn debug() {
+    let set = [Some(true), None].into_iter().collect::<ExhaustiveSet<_>>();
+    assert_eq!("{None, Some(true)}", format!("{set:?}"));
+}
+
+#[test]
+fn const_ops() {
+    const EMPTY: ExhaustiveSet<Direction> = ExhaustiveSet::new();
+    const FULL: ExhaustiveSet<Direction> = EMPTY.complement();
+    const LEN: usize = FULL.union(EMPTY).len();
+
+    assert_eq!(4, LEN);
+}
+
+#[test]
+fn subsets() {
+    assert_eq!(1, Subsets::<Infallible>::ALL.len());
+    assert_eq!(16, Subsets::<Direction>::ALL.len());
+
+    // same order as `[bool; N]`
+    for (index, (set, flags)) in Subsets::<Direction>::ALL
+        .into_iter()
+        .zip(<[bool; 4]>::ALL)
+        .enumerate()
+    {
+        let contains = Direction::ALL
+            .into_iter()
+            .map(|dir| set.contains(dir))
+            .collect::<Vec<_>>();
+        assert_eq!(flags.as_slice(), contains);
+        assert_eq!(index, set.index());
+        assert_eq!(Some(set), Subsets::<Direction>::from_index(index));
+    }
+    assert_eq!(None, Subsets::<Direction>::from_index(16));
+
+    // subsets are exhaustive themselves
+    assert_eq!(16, Subsets::<Subsets<bool>>::ALL.len());
+}
@@ -0,0 +1,72 @@
+#![expect(missing_docs, reason = "test module")]
+
+use const_exhaustive::{
+    ConstEq, Exhaustive, RangedLenU8, debug_verify, generic_array::GenericArray, typenum,
+    verify_exhaustive,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Exhaustive, ConstEq)]
+enum Enum {
+    A,
+    B(bool),
+    C { a: Option<bool>, b: [bool; 2] },
+}
+
+verify_exhaustive!(Enum);
+verify_exhaustive!(RangedLenU8<1, 6>);
+
+// `ALL` is fine, but `index` and `from_index` are wrong
+#[derive(Debug, Clone, Copy, PartialEq, ConstEq)]
+struct BadIndex(u8);
+
+unsafe impl Exhaustive for BadIndex {
+    type Num = typenum::U3;
+
+    const ALL: GenericArray<Self, Self::Num> =
+        GenericArray::from_array([Self(0), Self(1), Self(2)]);
+
+    fn index(self) -> usize {
+        usize::from(self.0) % 2
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        u8::try_from(index).ok().map(Self)
+    }
+}
+
+verify_exhaustive!(BadIndex);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Duplicate(u8);
+
+unsafe impl Exhaustive for Duplicate {
+    type Num = typenum::U2;
+
+    const ALL: GenericArray<Self, Self::Num> = GenericArray::from_array([Self(0), Self(0)]);
+
+    fn index(self) -> usize {
+        usize::from(self.0)
+    }
+}
+
+#[test]
+fn valid() {
+    debug_verify::<()>();
+    debug_verify::<bool>();
+    debug_verify::<Enum>();
+    debug_verify::<(Enum, Option<Enum>)>();
+    debug_verify::<[Enum; 2]>();
+    debug_verify::<RangedLenU8<1, 6>>();
+}
+
+#[test]
+#[should_panic = "does not match its position"]
+fn bad_index() {
+    debug_verify::<BadIndex>();
+}
+
+#[test]
+#[should_panic = "contains Duplicate(0) at both 0 and 1"]
+fn duplicate() {
+    debug_verify::<Duplicate>();
+}